@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    client::AocClient,
+    config::Config,
+    error::{Error, Result},
+};
+
+/// Prefix of the variables that register named sessions, as in
+/// `AOC_SESSION_ALICE=...`. `AOC_SESSION_ID` itself is the default session.
+const PREFIX: &str = "AOC_SESSION_";
+
+/// A named session whose inputs and answers are kept apart from everyone
+/// else's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    pub name: String,
+    session_id: String,
+}
+
+impl Account {
+    pub fn new(name: &str, session_id: &str) -> Self {
+        Account {
+            name: name.to_lowercase(),
+            session_id: session_id.trim().to_string(),
+        }
+    }
+
+    /// A client like `AocClient::from_config`, but with this account's
+    /// session.
+    pub fn client(&self, config: &Config) -> AocClient {
+        AocClient::from_config(config).with_session(&self.session_id)
+    }
+
+    /// Where this account's files go under `root`, e.g. `inputs/alice`.
+    pub fn dir<P: AsRef<Path>>(&self, root: P) -> PathBuf {
+        root.as_ref().join(&self.name)
+    }
+}
+
+/// The accounts registered in `vars`, by name.
+pub fn accounts_from<I: IntoIterator<Item = (String, String)>>(vars: I) -> Vec<Account> {
+    let mut accounts: Vec<Account> = vars
+        .into_iter()
+        .filter_map(|(var, value)| {
+            let name = var.strip_prefix(PREFIX)?;
+            let valid = !name.is_empty()
+                && name != "ID"
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            valid.then(|| Account::new(name, &value))
+        })
+        .collect();
+    accounts.sort_by(|a, b| a.name.cmp(&b.name));
+    accounts.dedup_by(|a, b| a.name == b.name);
+    accounts
+}
+
+/// The accounts registered in the environment or in `.env`.
+pub fn accounts() -> Vec<Account> {
+    dotenv::dotenv().ok();
+    accounts_from(std::env::vars())
+}
+
+/// The account registered as `name` among `accounts`, whatever its case.
+pub fn find(accounts: Vec<Account>, name: &str) -> Result<Account> {
+    let wanted = name.to_lowercase();
+    accounts
+        .into_iter()
+        .find(|a| a.name == wanted)
+        .ok_or_else(|| {
+            Error::Args(format!(
+                "no account named {name}; register it as {PREFIX}{}",
+                name.to_uppercase()
+            ))
+        })
+}
+
+// TESTS
+
+#[test]
+fn test_accounts_from() {
+    let vars = [
+        ("AOC_SESSION_ID", "default"),
+        ("AOC_SESSION_BOB", "bob-cookie\n"),
+        ("AOC_SESSION_ALICE", "alice-cookie"),
+        ("AOC_SESSION_", "nobody"),
+        ("AOC_SESSION_../X", "nope"),
+        ("AOC_BASE_URL", "http://localhost"),
+        ("AOC_COOKIE_SOURCES", "env:AOC_SESSION_ID,file:.cookie"),
+    ];
+    let accounts = accounts_from(vars.map(|(k, v)| (k.to_string(), v.to_string())));
+    assert_eq!(
+        accounts,
+        vec![
+            Account::new("alice", "alice-cookie"),
+            Account::new("BOB", "bob-cookie"),
+        ]
+    );
+    assert_eq!(accounts[1].dir("inputs"), Path::new("inputs/bob"));
+
+    assert_eq!(find(accounts.clone(), "BOB").unwrap().name, "bob");
+    assert_eq!(find(accounts.clone(), "Alice").unwrap().name, "alice");
+    assert!(matches!(find(accounts, "carol"), Err(Error::Args(_))));
+}
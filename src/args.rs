@@ -0,0 +1,173 @@
+use std::{io, path::PathBuf, str::FromStr};
+
+use crate::{
+    error::{Error, Result},
+    report::{DayReport, Format, Reporter},
+};
+
+/// Where a day binary reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputArg {
+    /// The cached input, fetched first if it is not there yet.
+    Cached,
+    File(PathBuf),
+    Stdin,
+    /// The first example from the puzzle, as saved by `aoc --puzzle`.
+    Example,
+}
+
+/// The command line every day binary understands:
+///
+/// ```text
+/// dayNN [--input PATH | - | --example] [--part 1|2] [--format FORMAT] [--NAME VALUE]...
+/// ```
+///
+/// where each `NAME` is one of the day's own puzzle parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: InputArg,
+    /// The only part to solve, or `None` for both.
+    pub part: Option<u8>,
+    /// Overrides the configured output format.
+    pub format: Option<Format>,
+    params: Vec<(String, String)>,
+}
+
+impl DayArgs {
+    /// Make sense of `args`, the command line without the program name.
+    /// `params` names the flags the day takes besides the common ones, e.g.
+    /// `&["row"]` for `--row N`.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I, params: &[&str]) -> Result<DayArgs> {
+        let mut parsed = DayArgs {
+            input: InputArg::Cached,
+            part: None,
+            format: None,
+            params: vec![],
+        };
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |what: &str| {
+                args.next()
+                    .ok_or_else(|| Error::Args(format!("{arg} needs {what}")))
+            };
+            match arg.as_str() {
+                "-" => parsed.input = InputArg::Stdin,
+                "--input" => {
+                    parsed.input = match value("a file, or - for stdin")?.as_str() {
+                        "-" => InputArg::Stdin,
+                        path => InputArg::File(PathBuf::from(path)),
+                    }
+                }
+                "--example" => parsed.input = InputArg::Example,
+                "--part" => {
+                    parsed.part = match value("the part to solve, 1 or 2")?.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        part => return Err(Error::Args(format!("there is no part {part}"))),
+                    }
+                }
+                "--format" => parsed.format = Some(value("text, json or markdown")?.parse()?),
+                flag => match flag.strip_prefix("--").filter(|name| params.contains(name)) {
+                    Some(name) => {
+                        let given = value("a value")?;
+                        parsed.params.push((name.to_string(), given));
+                    }
+                    None => return Err(Error::Args(format!("unknown argument {flag}"))),
+                },
+            }
+        }
+        Ok(parsed)
+    }
+
+    /// The process's own arguments, or exit explaining what is wrong with
+    /// them.
+    pub fn from_env(params: &[&str]) -> DayArgs {
+        DayArgs::parse(std::env::args().skip(1), params).unwrap_or_else(|e| e.exit())
+    }
+
+    /// Whether `part` is to be solved.
+    pub fn runs(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// The value given for `--name`, or `default` if there was none. The
+    /// last one given wins.
+    pub fn param<T: FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.params.iter().rev().find(|(n, _)| n == name) {
+            Some((_, value)) => value
+                .parse()
+                .map_err(|_| Error::Args(format!("--{name} cannot be {value:?}"))),
+            None => Ok(default),
+        }
+    }
+
+    /// Print `report` in the format asked for, or else the configured one,
+    /// or exit if it cannot be.
+    pub fn report(&self, report: &DayReport) {
+        let printed = crate::config().and_then(|config| {
+            let format = self.format.unwrap_or(config.output.format);
+            let mut reporter =
+                Reporter::new(io::stdout(), format).with_timings(config.output.timings);
+            reporter.add(report)?;
+            reporter.finish().map(|_| ())
+        });
+        printed.unwrap_or_else(|e| e.exit())
+    }
+
+    /// The input for `day` of `year`, from wherever the arguments say.
+    pub fn read_input(&self, year: u16, day: usize) -> Result<String> {
+        match &self.input {
+            InputArg::Cached => crate::read_input(year, day),
+            InputArg::File(path) => Ok(std::fs::read_to_string(path)?),
+            InputArg::Stdin => Ok(io::read_to_string(io::stdin())?),
+            InputArg::Example => crate::read_example(year, day),
+        }
+    }
+}
+
+// TESTS
+
+#[cfg(test)]
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+#[test]
+fn test_day_args() {
+    let parsed = DayArgs::parse(args(""), &[]).unwrap();
+    assert_eq!(parsed.input, InputArg::Cached);
+    assert!(parsed.runs(1) && parsed.runs(2));
+
+    let parsed = DayArgs::parse(args("--input my.txt --part 2 --format md"), &[]).unwrap();
+    assert_eq!(parsed.input, InputArg::File(PathBuf::from("my.txt")));
+    assert_eq!(parsed.format, Some(Format::Markdown));
+    assert!(!parsed.runs(1) && parsed.runs(2));
+    assert_eq!(
+        DayArgs::parse(args("-"), &[]).unwrap().input,
+        InputArg::Stdin
+    );
+    assert_eq!(
+        DayArgs::parse(args("--input -"), &[]).unwrap().input,
+        InputArg::Stdin
+    );
+
+    let parsed = DayArgs::parse(args("--example --row 10 --row 11"), &["row", "max"]).unwrap();
+    assert_eq!(parsed.input, InputArg::Example);
+    assert_eq!(parsed.param("row", 2_000_000).unwrap(), 11);
+    assert_eq!(parsed.param("max", 4_000_000).unwrap(), 4_000_000);
+
+    for bad in [
+        "--part 3",
+        "--part",
+        "--row 10",
+        "--verbose",
+        "--format xml",
+    ] {
+        assert!(matches!(
+            DayArgs::parse(args(bad), &[]),
+            Err(Error::Args(_))
+        ));
+    }
+    let parsed = DayArgs::parse(args("--row ten"), &["row"]).unwrap();
+    assert!(matches!(parsed.param("row", 0), Err(Error::Args(_))));
+}
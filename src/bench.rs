@@ -0,0 +1,343 @@
+use std::{
+    fmt, fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    report::format_elapsed,
+    solution::Solution,
+};
+
+/// Slowdowns smaller than this are put down to noise, however large they
+/// are relative to the baseline.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+/// Summary of the timings of one step over every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+    /// Median absolute deviation from the median.
+    pub mad_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut ns: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        ns.sort_unstable();
+        let middle = median(&ns);
+        let mut deviations: Vec<u64> = ns.iter().map(|&n| n.abs_diff(middle)).collect();
+        deviations.sort_unstable();
+        Stats {
+            median_ns: middle,
+            min_ns: ns[0],
+            max_ns: ns[ns.len() - 1],
+            mad_ns: median(&deviations),
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn spread(&self) -> Duration {
+        Duration::from_nanos(self.mad_ns)
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ± {}",
+            format_elapsed(self.median()),
+            format_elapsed(self.spread())
+        )
+    }
+}
+
+/// The middle of `sorted`, or the mean of the two middle values.
+fn median(sorted: &[u64]) -> u64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+/// How long one day takes to parse its input and to solve each part, on
+/// the input of one account if it is not the default session's.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ]
+    }
+
+    /// `Day 08`, or `Day 08 [alice]` for a named account.
+    pub fn label(&self) -> String {
+        Self::label_for(self.day, &self.account)
+    }
+
+    fn label_for(day: usize, account: &Option<String>) -> String {
+        match account {
+            Some(account) => format!("Day {day:0>2} [{account}]"),
+            None => format!("Day {day:0>2}"),
+        }
+    }
+}
+
+impl fmt::Display for DayBench {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: parse {}, part 1 {}, part 2 {}",
+            self.label(),
+            self.parse,
+            self.part1,
+            self.part2
+        )
+    }
+}
+
+/// Runs a day a few times to warm up, then times it over a number of
+/// iterations.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    warmup: usize,
+    iterations: usize,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Bench {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+impl Bench {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    pub fn run<S: Solution>(&self, input: &str) -> DayBench {
+        let mut samples = [vec![], vec![], vec![]];
+        for i in 0..self.warmup + self.iterations {
+            let start = Instant::now();
+            let data = black_box(S::parse(input));
+            let parsed = Instant::now();
+            black_box(S::part1(&data));
+            let solved1 = Instant::now();
+            black_box(S::part2(&data));
+            let solved2 = Instant::now();
+            if i >= self.warmup {
+                samples[0].push(parsed - start);
+                samples[1].push(solved1 - parsed);
+                samples[2].push(solved2 - solved1);
+            }
+        }
+        DayBench {
+            year: S::YEAR,
+            day: S::DAY,
+            account: None,
+            iterations: self.iterations,
+            parse: Stats::from_samples(&samples[0]),
+            part1: Stats::from_samples(&samples[1]),
+            part2: Stats::from_samples(&samples[2]),
+        }
+    }
+}
+
+/// Write `results` to `path` as JSON, for use as a baseline later.
+pub fn save_results<P: AsRef<Path>>(path: P, results: &[DayBench]) -> Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|e| Error::CacheDir(dir.to_path_buf(), e))?;
+    }
+    let json = serde_json::to_string_pretty(results).expect("benchmarks always serialise");
+    fs::write(path, json + "\n")?;
+    Ok(())
+}
+
+pub fn load_results<P: AsRef<Path>>(path: P) -> Result<Vec<DayBench>> {
+    serde_json::from_str(&fs::read_to_string(path)?)
+        .map_err(|_| Error::Parse("the benchmark baseline is not valid JSON"))
+}
+
+/// A step that got slower than the baseline allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: usize,
+    pub account: Option<String>,
+    pub step: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ratio = self.current.as_secs_f64() / self.baseline.as_secs_f64().max(1e-9);
+        write!(
+            f,
+            "{}, {}: {} -> {} ({:+.0}%)",
+            DayBench::label_for(self.day, &self.account),
+            self.step,
+            format_elapsed(self.baseline),
+            format_elapsed(self.current),
+            (ratio - 1.0) * 100.0
+        )
+    }
+}
+
+/// The steps whose median is more than `threshold` (0.1 for 10%) slower
+/// than in `baseline`. Days missing from `baseline`, for the same account,
+/// are not compared.
+pub fn regressions(current: &[DayBench], baseline: &[DayBench], threshold: f64) -> Vec<Regression> {
+    let mut found = vec![];
+    for now in current {
+        let Some(before) = baseline
+            .iter()
+            .find(|b| (b.year, b.day, &b.account) == (now.year, now.day, &now.account))
+        else {
+            continue;
+        };
+        for ((step, after), (_, was)) in now.steps().into_iter().zip(before.steps()) {
+            let (current, baseline) = (after.median(), was.median());
+            if current > baseline.mul_f64(1.0 + threshold) && current - baseline > NOISE_FLOOR {
+                found.push(Regression {
+                    year: now.year,
+                    day: now.day,
+                    account: now.account.clone(),
+                    step,
+                    baseline,
+                    current,
+                });
+            }
+        }
+    }
+    found
+}
+
+// TESTS
+
+#[cfg(test)]
+fn micros(samples: &[u64]) -> Vec<Duration> {
+    samples
+        .iter()
+        .map(|&us| Duration::from_micros(us))
+        .collect()
+}
+
+#[test]
+fn test_stats() {
+    let stats = Stats::from_samples(&micros(&[120, 100, 400, 110, 105]));
+    assert_eq!(stats.median(), Duration::from_micros(110));
+    assert_eq!(stats.min_ns, 100_000);
+    assert_eq!(stats.max_ns, 400_000);
+    // Deviations are 10, 10, 290, 0 and 5 us.
+    assert_eq!(stats.spread(), Duration::from_micros(10));
+    assert_eq!(stats.to_string(), "110 us ± 10 us");
+    let even = Stats::from_samples(&micros(&[10, 20, 30, 40]));
+    assert_eq!(even.median(), Duration::from_micros(25));
+}
+
+#[test]
+fn test_bench_run() {
+    let bench = Bench::new().with_warmup(1).with_iterations(4);
+    let result = bench.run::<crate::day01::Day01>("1000\n2000\n\n3000\n\n4000\n\n5000\n");
+    assert_eq!((result.year, result.day, result.iterations), (2022, 1, 4));
+    assert!(result.parse.min_ns <= result.parse.median_ns);
+    assert!(result.parse.median_ns <= result.parse.max_ns);
+}
+
+#[test]
+fn test_regressions() {
+    let day = |day, us: [u64; 3]| {
+        let stats = |us| Stats::from_samples(&micros(&[us]));
+        DayBench {
+            year: 2022,
+            day,
+            account: None,
+            iterations: 1,
+            parse: stats(us[0]),
+            part1: stats(us[1]),
+            part2: stats(us[2]),
+        }
+    };
+    let baseline = vec![day(1, [100, 1_000, 10_000]), day(2, [100, 100, 100])];
+    let current = vec![
+        day(1, [140, 1_050, 12_000]),
+        day(2, [100, 100, 100]),
+        day(3, [9_999, 9_999, 9_999]),
+    ];
+    let found = regressions(&current, &baseline, 0.1);
+    // Parse is 40% slower, but only by 40 us.
+    assert_eq!(
+        found,
+        vec![Regression {
+            year: 2022,
+            day: 1,
+            account: None,
+            step: "part 2",
+            baseline: Duration::from_micros(10_000),
+            current: Duration::from_micros(12_000),
+        }]
+    );
+    assert_eq!(
+        found[0].to_string(),
+        "Day 01, part 2: 10 ms -> 12 ms (+20%)"
+    );
+    // Another account's input is only held to its own times.
+    let alice = |benches: &[DayBench]| -> Vec<DayBench> {
+        let mut benches = benches.to_vec();
+        benches
+            .iter_mut()
+            .for_each(|b| b.account = Some("alice".to_string()));
+        benches
+    };
+    assert!(regressions(&alice(&current), &baseline, 0.1).is_empty());
+    assert_eq!(
+        regressions(&alice(&current), &alice(&baseline), 0.1)[0].to_string(),
+        "Day 01 [alice], part 2: 10 ms -> 12 ms (+20%)"
+    );
+
+    let dir = crate::testing::temp_dir("bench");
+    let path = dir.join("baselines/2022.json");
+    save_results(&path, &current).unwrap();
+    assert_eq!(load_results(&path).unwrap(), current);
+}
@@ -0,0 +1,333 @@
+use std::{io::Write, str::FromStr};
+
+use aoc2022::{
+    account::{self, Account},
+    account_ledger, answer_ledger,
+    bench::{self, Bench},
+    client::Session,
+    crypto::CacheKey,
+    fetch_input_at_unlock, get_leaderboard, get_puzzle, invalidate_account_input, invalidate_input,
+    prefetch::Prefetched,
+    prefetch_inputs, read_account_input, read_input, rekey_inputs,
+    report::{Format, Reporter},
+    run::RunAll,
+    schedule::{format_countdown, next_locked_day},
+    submit_answer, validate_session, Error,
+};
+
+pub fn main() {
+    let config = aoc2022::config().unwrap_or_else(|e| e.exit());
+    let registry = aoc2022::registry();
+    let mut year = config.year;
+    let mut refetch = false;
+    let mut puzzle = false;
+    let mut prefetch = false;
+    let mut check_session = false;
+    let mut wait = false;
+    let mut leaderboard: Option<u64> = None;
+    let mut submit: Option<u8> = None;
+    let mut format = config.output.format;
+    let mut benchmark: Option<Bench> = None;
+    let mut save: Option<String> = None;
+    let mut baseline: Option<String> = None;
+    let mut threshold = 10.0;
+    let mut all = false;
+    let mut sequential = false;
+    let mut accounts: Vec<Account> = vec![];
+    let mut days: Vec<usize> = vec![];
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--refetch" => refetch = true,
+            "--year" => year = value(&mut args, &arg, "a year, e.g. 2022"),
+            "--puzzle" => puzzle = true,
+            "--prefetch" => prefetch = true,
+            "--check-session" => check_session = true,
+            // Wait for the days to unlock (by default, the next one to),
+            // fetch their inputs the moment they do and solve them.
+            "--wait" => wait = true,
+            "--leaderboard" => {
+                leaderboard = Some(value(&mut args, &arg, "the leaderboard's number"));
+            }
+            // Re-encrypt the cached inputs with AOC_NEW_CACHE_KEY, then
+            // AOC_CACHE_KEY has to be updated to match.
+            "--rekey" => {
+                let key = CacheKey::from_env("AOC_NEW_CACHE_KEY")
+                    .and_then(|key| key.ok_or(Error::Crypto("AOC_NEW_CACHE_KEY is not set")))
+                    .unwrap_or_else(|e| e.exit());
+                let count = rekey_inputs(Some(key)).unwrap_or_else(|e| e.exit());
+                println!("Re-encrypted {count} input(s)");
+                return;
+            }
+            "--decrypt" => {
+                let count = rekey_inputs(None).unwrap_or_else(|e| e.exit());
+                println!("Decrypted {count} input(s)");
+                return;
+            }
+            // Run against the inputs of named accounts instead of the
+            // default session's, e.g. to catch solutions that only work for
+            // one of them.
+            "--account" => {
+                let name: String = value(&mut args, &arg, "an account name");
+                let found = account::find(account::accounts(), &name);
+                accounts.push(found.unwrap_or_else(|e| e.exit()));
+            }
+            "--all-accounts" => accounts = account::accounts(),
+            // Time parsing and each part over repeated runs instead of
+            // solving once, e.g. `--bench --iterations 50 --save b.json`, and
+            // fail if any step got slower than `--baseline b.json` by more
+            // than `--threshold` percent.
+            "--bench" => benchmark = Some(benchmark.unwrap_or_default()),
+            "--iterations" | "--warmup" => {
+                let n = value(&mut args, &arg, "a number of runs");
+                let b = benchmark.take().unwrap_or_default();
+                benchmark = Some(match arg.as_str() {
+                    "--iterations" => b.with_iterations(n),
+                    _ => b.with_warmup(n),
+                });
+            }
+            "--save" => save = Some(value(&mut args, &arg, "a file")),
+            "--baseline" => baseline = Some(value(&mut args, &arg, "a file")),
+            "--threshold" => threshold = value(&mut args, &arg, "a percentage, e.g. 10"),
+            // Solve the days side by side on the rayon pool, heavy ones
+            // first, and sum up at the end. A day that panics does not stop
+            // the others.
+            "--all" => all = true,
+            "--sequential" => sequential = true,
+            "--format" => format = value(&mut args, &arg, "text, json or markdown"),
+            "--submit" => {
+                submit = match value(&mut args, &arg, "the part to submit, 1 or 2") {
+                    part @ (1 | 2) => Some(part),
+                    part => Error::Args(format!("there is no part {part}")).exit(),
+                }
+            }
+            day => match day.parse() {
+                Ok(day) => days.push(day),
+                Err(_) => Error::Args(format!("unknown argument {day}")).exit(),
+            },
+        }
+    }
+    if submit.is_some() && !accounts.is_empty() {
+        Error::Args("--submit only works with the default session".to_string()).exit();
+    }
+    let targets: Vec<Option<&Account>> = if accounts.is_empty() {
+        vec![None]
+    } else {
+        accounts.iter().map(Some).collect()
+    };
+    if check_session {
+        for &account in targets.iter() {
+            let label = account.map_or("default".to_string(), |a| a.name.clone());
+            match validate_session(account) {
+                Ok(Session::LoggedIn(user)) => println!("Session {label}: logged in as {user}"),
+                Ok(Session::Expired) => println!("Session {label}: expired"),
+                Err(e) => eprintln!("Session {label}: {e}"),
+            }
+        }
+        return;
+    }
+    if let Some(id) = leaderboard {
+        let leaderboard = get_leaderboard(year, id).unwrap_or_else(|e| e.exit());
+        if days.is_empty() {
+            print!("{}", leaderboard.render_standings());
+        }
+        for day in days {
+            println!("Day {day:0>2}");
+            print!("{}", leaderboard.render_day(day));
+        }
+        return;
+    }
+    if prefetch {
+        if days.is_empty() {
+            days = (1..=25).collect();
+        }
+        for (day, result) in prefetch_inputs(year, &days).unwrap_or_else(|e| e.exit()) {
+            match result {
+                Ok(Prefetched::Fetched) => println!("Day {day:0>2}: fetched"),
+                Ok(Prefetched::Cached) => println!("Day {day:0>2}: already cached"),
+                Ok(Prefetched::Locked) => println!("Day {day:0>2}: not unlocked yet"),
+                Err(e) => eprintln!("Day {day:0>2}: {e}"),
+            }
+        }
+        return;
+    }
+    if wait {
+        if days.is_empty() {
+            let next = next_locked_day(year, std::time::SystemTime::now());
+            days.push(next.unwrap_or_else(|| panic!("Every day has unlocked already")));
+        }
+        for &day in days.iter() {
+            let fetched = fetch_input_at_unlock(year, day, |left| {
+                print!("\rDay {day:0>2} unlocks in {}", format_countdown(left));
+                std::io::stdout().flush().unwrap();
+            });
+            println!("\rDay {day:0>2} unlocked            ");
+            if let Err(e) = fetched {
+                eprintln!("Day {day:0>2}: {e}");
+            }
+        }
+    }
+    if days.is_empty() {
+        days = registry.days(year).collect();
+    }
+
+    if let Some(benchmark) = benchmark {
+        let mut results = vec![];
+        for day in days {
+            let Some(solution) = registry.get(year, day) else {
+                eprintln!("Day {day:0>2}: no solution registered");
+                continue;
+            };
+            for &account in targets.iter() {
+                match load_input(account, year, day, refetch) {
+                    Ok(input) => {
+                        let mut result = solution.bench(&input, &benchmark);
+                        result.account = account.map(|a| a.name.clone());
+                        println!("{result}");
+                        results.push(result);
+                    }
+                    Err(e) => eprintln!("{}: {e}", label(day, account)),
+                }
+            }
+        }
+        if let Some(path) = save {
+            bench::save_results(&path, &results).unwrap_or_else(|e| e.exit());
+        }
+        if let Some(path) = baseline {
+            let baseline = bench::load_results(&path).unwrap_or_else(|e| e.exit());
+            let found = bench::regressions(&results, &baseline, threshold / 100.0);
+            for regression in found.iter() {
+                eprintln!("Regression: {regression}");
+            }
+            if !found.is_empty() {
+                std::process::exit(1);
+            }
+            println!("No regressions against {path}");
+        }
+        return;
+    }
+    if all {
+        let run = RunAll::new().with_sequential(sequential);
+        let mut reporter =
+            Reporter::new(std::io::stdout(), format).with_timings(config.output.timings);
+        let mut failed = 0;
+        // One run per account, so each one's answers can be checked
+        // against the others'.
+        for &account in targets.iter() {
+            let input = |day| load_input(account, year, day, refetch);
+            let summary = run.run(&registry, year, &days, input);
+            for report in summary.reports() {
+                let mut report = report.clone();
+                report.account = account.map(|a| a.name.clone());
+                reporter.add(&report).unwrap_or_else(|e| e.exit());
+            }
+            let mut rendered = summary.render();
+            if let Some(account) = account {
+                rendered = format!("[{}]\n{rendered}", account.name);
+            }
+            match format {
+                Format::Text => print!("{rendered}"),
+                _ => eprint!("{rendered}"),
+            }
+            failed += summary.failed();
+        }
+        reporter.finish().unwrap_or_else(|e| e.exit());
+        if failed > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let mut reporter = Reporter::new(std::io::stdout(), format).with_timings(config.output.timings);
+    // Keep everything but the answers out of JSON and markdown output, so it
+    // can be fed to other tools as it is.
+    let note = |line: String| match format {
+        Format::Text => println!("{line}"),
+        _ => eprintln!("{line}"),
+    };
+    for day in days {
+        if puzzle {
+            match get_puzzle(year, day) {
+                Ok(p) => println!(
+                    "Day {day:0>2}: saved description and {} example(s)",
+                    p.examples.len()
+                ),
+                Err(e) => eprintln!("Day {day:0>2}: {e}"),
+            }
+            continue;
+        }
+        let Some(solution) = registry.get(year, day) else {
+            eprintln!("Day {day:0>2}: no solution registered");
+            continue;
+        };
+        for &account in targets.iter() {
+            let label = label(day, account);
+            let input = match load_input(account, year, day, refetch) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{label}: {e}");
+                    continue;
+                }
+            };
+            let mut report = solution.solve(&input);
+            report.account = account.map(|a| a.name.clone());
+            reporter.add(&report).unwrap_or_else(|e| e.exit());
+            let ledger = match account {
+                Some(account) => account_ledger(account, year, day),
+                None => answer_ledger(year, day),
+            };
+            if let Ok(ledger) = ledger {
+                for solved in report.parts.iter() {
+                    let part = solved.part;
+                    match ledger.accepted(part) {
+                        Some(accepted) if accepted != solved.answer.to_string().trim() => note(
+                            format!("{label}, part {part}: the accepted answer was {accepted}"),
+                        ),
+                        _ => (),
+                    }
+                }
+            }
+            if let Some((part, answer)) = submit.and_then(|p| Some((p, report.answer(p)?))) {
+                match submit_answer(year, day, part, answer) {
+                    Ok(outcome) => note(format!("{label}, part {part} submitted: {outcome}")),
+                    Err(e) => eprintln!("{label}: {e}"),
+                }
+            }
+        }
+    }
+    reporter.finish().unwrap_or_else(|e| e.exit());
+}
+
+/// `Day 08`, or `Day 08 [alice]` for a named account.
+fn label(day: usize, account: Option<&Account>) -> String {
+    match account {
+        Some(account) => format!("Day {day:0>2} [{}]", account.name),
+        None => format!("Day {day:0>2}"),
+    }
+}
+
+/// The input for `day` of `year`, of `account` or else of the default
+/// session, fetched again first with `refetch`.
+fn load_input(
+    account: Option<&Account>,
+    year: u16,
+    day: usize,
+    refetch: bool,
+) -> aoc2022::Result<String> {
+    if refetch {
+        match account {
+            Some(account) => invalidate_account_input(account, year, day)?,
+            None => invalidate_input(year, day)?,
+        }
+    }
+    match account {
+        Some(account) => read_account_input(account, year, day),
+        None => read_input(year, day),
+    }
+}
+
+/// The value given after `flag`, or exit saying it needs `what`.
+fn value<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str, what: &str) -> T {
+    args.next()
+        .and_then(|given| given.parse().ok())
+        .unwrap_or_else(|| Error::Args(format!("{flag} needs {what}")).exit())
+}
@@ -1,86 +1,14 @@
-use aoc2022::check_or_get_input;
-use sscanf::sscanf;
+use aoc2022::{args::DayArgs, day04, report::DayReport};
 
-fn prep(input: &str) -> Vec<(i32, i32, i32, i32)> {
-    input
-        .lines()
-        .map(|line| sscanf!(line, "{i32}-{i32},{i32}-{i32}").expect("Bad input"))
-        .collect()
-}
-
-fn contains(regions: &(i32, i32, i32, i32)) -> bool {
-    match regions {
-        (r1l, r1h, r2l, r2h) => (r1l <= r2l && r1h >= r2h) || (r2l <= r1l && r2h >= r1h),
-    }
-}
-
-fn part1(data: &Vec<(i32, i32, i32, i32)>) -> i32 {
-    let mut count = 0;
-    for regions in data {
-        if contains(regions) {
-            count += 1
-        }
-    }
-    count
-}
-
-fn overlaps(regions: &(i32, i32, i32, i32)) -> bool {
-    match regions {
-        (r1l, r1h, r2l, r2h) => (r1l < r2l && r2l <= r1h) || (r1l >= r2l && r1l <= r2h),
+pub fn main() {
+    let args = DayArgs::from_env(&[]);
+    let data = day04::parse(&args.read_input(2022, 4).unwrap_or_else(|e| e.exit()));
+    let mut report = DayReport::new(2022, 4);
+    if args.runs(1) {
+        report.time(1, || day04::part1(&data));
     }
-}
-
-fn part2(data: &Vec<(i32, i32, i32, i32)>) -> i32 {
-    let mut count = 0;
-    for regions in data {
-        if overlaps(regions) {
-            count += 1
-        }
+    if args.runs(2) {
+        report.time(2, || day04::part2(&data));
     }
-    count
-}
-
-pub fn main() {
-    let filename: String = check_or_get_input(4);
-    let data = prep(
-        std::fs::read_to_string(filename)
-            .expect("Day 4: cannot read input")
-            .as_str(),
-    );
-    println!("Day 4, part 1: {}", part1(&data));
-    println!("Day 4, part 2: {}", part2(&data));
-}
-
-// TESTS
-
-#[allow(dead_code)]
-const DAY4_EXAMPLE: &str = r#"2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8"#;
-
-#[test]
-fn test_day04_prep() {
-    assert_eq!(
-        prep(DAY4_EXAMPLE),
-        [
-            (2, 4, 6, 8),
-            (2, 3, 4, 5),
-            (5, 7, 7, 9),
-            (2, 8, 3, 7),
-            (6, 6, 4, 6),
-            (2, 6, 4, 8)
-        ]
-    );
-}
-#[test]
-fn test_day04_part1() {
-    assert_eq!(part1(&prep(DAY4_EXAMPLE)), 2);
-}
-
-#[test]
-fn test_day04_part2() {
-    assert_eq!(part2(&prep(DAY4_EXAMPLE)), 4);
+    args.report(&report);
 }
@@ -0,0 +1,219 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use regex::Regex;
+use reqwest::{
+    blocking::{RequestBuilder, Response},
+    header::{COOKIE, USER_AGENT},
+    StatusCode,
+};
+
+use crate::{
+    config::Config,
+    error::{Error, Result},
+};
+
+/// Sessions already found to be valid in this run, with the site they are
+/// valid for.
+static VALIDATED: Mutex<Option<HashSet<(String, String)>>> = Mutex::new(None);
+
+/// Whom the site takes a session cookie to belong to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Session {
+    /// Logged in, with the name the site shows.
+    LoggedIn(String),
+    Expired,
+}
+
+/// Tell from any page of the site whether the session was logged in.
+pub fn parse_session(page: &str) -> Session {
+    let user = Regex::new(r#"<div class="user">([^<]*)"#).unwrap();
+    match user.captures(page) {
+        Some(c) if !c[1].trim().is_empty() => Session::LoggedIn(c[1].trim().to_string()),
+        _ => Session::Expired,
+    }
+}
+
+/// Authenticated access to the Advent of Code site, or anything that looks
+/// like it.
+pub struct AocClient {
+    base_url: String,
+    year: u16,
+    session_id: Option<String>,
+    user_agent: String,
+    check_session: bool,
+    http: reqwest::blocking::Client,
+}
+
+impl AocClient {
+    pub const DEFAULT_BASE_URL: &'static str = "https://adventofcode.com";
+    pub const USER_AGENT: &'static str = concat!("aoc2022/", env!("CARGO_PKG_VERSION"));
+
+    pub fn new(session_id: Option<String>) -> Self {
+        AocClient {
+            base_url: Self::DEFAULT_BASE_URL.to_string(),
+            year: 2022,
+            session_id,
+            user_agent: Self::USER_AGENT.to_string(),
+            check_session: false,
+            http: reqwest::blocking::Client::new(),
+        }
+    }
+
+    /// Session from `AOC_SESSION_ID`, base URL from `AOC_BASE_URL` and
+    /// contact details from `AOC_CONTACT`, if set, either in the environment
+    /// or in `.env`. A missing session only becomes an error once a request
+    /// needs it, and the session is checked before its first use.
+    pub fn from_env() -> Self {
+        Self::from_config(&Config::default())
+    }
+
+    /// Like `from_env`, but with the year and session sources of `config`.
+    pub fn from_config(config: &Config) -> Self {
+        let mut client = AocClient::new(config.session_id())
+            .with_year(config.year)
+            .with_session_check(true);
+        if let Ok(url) = dotenv::var("AOC_BASE_URL") {
+            client = client.with_base_url(&url);
+        }
+        if let Ok(contact) = dotenv::var("AOC_CONTACT") {
+            client = client.with_contact(&contact);
+        }
+        client
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Which year's event requests go to.
+    pub fn with_year(mut self, year: u16) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn with_session(mut self, session_id: &str) -> Self {
+        self.session_id = Some(session_id.to_string());
+        self
+    }
+
+    /// Put `contact` (an email address or a repository URL) in the
+    /// User-Agent, so the site's maintainers know whom to ask about the
+    /// traffic.
+    pub fn with_contact(mut self, contact: &str) -> Self {
+        self.user_agent = format!("{} ({})", Self::USER_AGENT, contact.trim());
+        self
+    }
+
+    /// Make sure the session is still valid before the first request that
+    /// uses it, once per run. An expired session then fails that request
+    /// with `Error::SessionExpired` rather than with whatever page the site
+    /// sends back.
+    pub fn with_session_check(mut self, check_session: bool) -> Self {
+        self.check_session = check_session;
+        self
+    }
+
+    fn session_id(&self) -> Result<&str> {
+        self.session_id.as_deref().ok_or(Error::MissingSession)
+    }
+
+    fn authenticated(&self, request: RequestBuilder) -> Result<Response> {
+        let response = request
+            .header(COOKIE, format!("session={}", self.session_id()?))
+            .header(USER_AGENT, &self.user_agent)
+            .send()?;
+        check_status(response)
+    }
+
+    /// Who the site thinks the session belongs to, judging by the year's
+    /// calendar page.
+    pub fn validate_session(&self) -> Result<Session> {
+        let request = self.http.get(format!("{}/{}", self.base_url, self.year));
+        Ok(parse_session(&self.authenticated(request)?.text()?))
+    }
+
+    fn ensure_session(&self) -> Result<()> {
+        if !self.check_session {
+            return Ok(());
+        }
+        let key = (self.base_url.clone(), self.session_id()?.to_string());
+        // Held throughout, so concurrent requests wait for one check.
+        let mut validated = VALIDATED.lock().unwrap();
+        let validated = validated.get_or_insert_with(HashSet::new);
+        if !validated.contains(&key) {
+            match self.validate_session()? {
+                Session::LoggedIn(_) => validated.insert(key),
+                Session::Expired => return Err(Error::SessionExpired),
+            };
+        }
+        Ok(())
+    }
+
+    /// GET `path`, failing on anything but a 200.
+    pub fn get(&self, path: &str) -> Result<Response> {
+        self.ensure_session()?;
+        self.authenticated(self.http.get(format!("{}{path}", self.base_url)))
+    }
+
+    /// POST `form` to `path`, failing on anything but a 200.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response> {
+        self.ensure_session()?;
+        let request = self.http.post(format!("{}{path}", self.base_url));
+        self.authenticated(request.form(form))
+    }
+}
+
+fn check_status(response: Response) -> Result<Response> {
+    match response.status() {
+        StatusCode::OK => Ok(response),
+        status => Err(Error::Http(status)),
+    }
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const CALENDAR_PAGE: &str = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><nav><ul><li><a href="/2022/about">[About]</a></li></ul></nav><div class="user">Alice <span class="star-count">8*</span></div></div></header>"#;
+
+#[allow(dead_code)]
+const LOGGED_OUT_PAGE: &str = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><nav><ul><li><a href="/2022/auth/login">[Log In]</a></li></ul></nav></div></header>"#;
+
+#[test]
+fn test_parse_session() {
+    assert_eq!(
+        parse_session(CALENDAR_PAGE),
+        Session::LoggedIn("Alice".to_string())
+    );
+    assert_eq!(parse_session(LOGGED_OUT_PAGE), Session::Expired);
+}
+
+#[test]
+fn test_session_check() {
+    let server = crate::testing::Server::start(vec![
+        (200, CALENDAR_PAGE),
+        (200, "1000\n"),
+        (200, "2000\n"),
+        (200, LOGGED_OUT_PAGE),
+    ]);
+    let client = AocClient::new(Some("abc123".to_string()))
+        .with_base_url(&server.url)
+        .with_session_check(true);
+    client.get("/2022/day/1/input").unwrap();
+    client.get("/2022/day/2/input").unwrap();
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("GET /2022 "));
+
+    let expired = AocClient::new(Some("old".to_string()))
+        .with_base_url(&server.url)
+        .with_session_check(true);
+    assert!(matches!(
+        expired.get("/2022/day/1/input"),
+        Err(Error::SessionExpired)
+    ));
+}
@@ -0,0 +1,249 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    error::{Error, Result},
+    report::Format,
+};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// One place to look for the session cookie.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum SessionSource {
+    /// An environment variable, or the same name in `.env`.
+    Var(String),
+    /// A file holding nothing but the cookie, as other Advent of Code tools
+    /// keep it.
+    File(PathBuf),
+}
+
+impl SessionSource {
+    /// `env:NAME` or `file:PATH`, where a leading `~/` in `PATH` stands for
+    /// the home directory.
+    pub fn parse(source: &str) -> Result<SessionSource> {
+        match source.trim().split_once(':') {
+            Some(("env", var)) if !var.is_empty() => Ok(SessionSource::Var(var.to_string())),
+            Some(("file", path)) if !path.is_empty() => {
+                let path = match (path.strip_prefix("~/"), std::env::var("HOME")) {
+                    (Some(rest), Ok(home)) => Path::new(&home).join(rest),
+                    _ => PathBuf::from(path),
+                };
+                Ok(SessionSource::File(path))
+            }
+            _ => Err(Error::Config(format!(
+                "session source {source:?} is neither env:NAME nor file:PATH"
+            ))),
+        }
+    }
+
+    pub fn lookup(&self) -> Option<String> {
+        let found = match self {
+            SessionSource::Var(var) => dotenv::var(var).ok(),
+            SessionSource::File(path) => fs::read_to_string(path).ok(),
+        };
+        found
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+}
+
+impl TryFrom<String> for SessionSource {
+    type Error = Error;
+
+    fn try_from(source: String) -> Result<Self> {
+        SessionSource::parse(&source)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Output {
+    /// Print how long each day took to solve.
+    pub timings: bool,
+    /// `text`, `json` or `markdown`.
+    pub format: Format,
+}
+
+/// Project settings from `aoc.toml`, all optional:
+///
+/// ```toml
+/// year = 2022
+/// input_dir = "inputs"
+/// fixture_dir = "fixtures"
+/// answer_dir = "answers"
+/// leaderboard_dir = "leaderboards"
+/// session = ["env:AOC_SESSION_ID", "file:~/.config/aocd/token"]
+///
+/// [output]
+/// timings = true
+/// format = "markdown"
+/// ```
+///
+/// Each can be overridden from the environment, see `with_overrides`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: u16,
+    pub input_dir: PathBuf,
+    pub fixture_dir: PathBuf,
+    pub answer_dir: PathBuf,
+    pub leaderboard_dir: PathBuf,
+    /// Where to look for the session cookie, in order.
+    pub session: Vec<SessionSource>,
+    pub output: Output,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: 2022,
+            input_dir: PathBuf::from("./inputs"),
+            fixture_dir: PathBuf::from("./fixtures"),
+            answer_dir: PathBuf::from("./answers"),
+            leaderboard_dir: PathBuf::from("./leaderboards"),
+            session: vec![SessionSource::Var("AOC_SESSION_ID".to_string())],
+            output: Output::default(),
+        }
+    }
+}
+
+impl Config {
+    pub fn from_toml(text: &str) -> Result<Config> {
+        toml::from_str(text).map_err(|e| Error::Config(e.message().to_string()))
+    }
+
+    /// The settings in `path`, or the defaults if there is no such file,
+    /// with the environment's overrides on top.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Config> {
+        let config = match fs::read_to_string(path) {
+            Ok(text) => Config::from_toml(&text)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(e) => return Err(e.into()),
+        };
+        config.with_overrides(|var| dotenv::var(var).ok())
+    }
+
+    /// Apply `AOC_YEAR`, `AOC_INPUT_DIR`, `AOC_FIXTURE_DIR`, `AOC_ANSWER_DIR`,
+    /// `AOC_LEADERBOARD_DIR`, `AOC_COOKIE_SOURCES` (comma-separated),
+    /// `AOC_TIMINGS` and `AOC_FORMAT`, looked up with `var`.
+    pub fn with_overrides(mut self, var: impl Fn(&str) -> Option<String>) -> Result<Config> {
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = year
+                .trim()
+                .parse()
+                .map_err(|_| Error::Config(format!("AOC_YEAR is not a year: {year}")))?;
+        }
+        let dirs = [
+            ("AOC_INPUT_DIR", &mut self.input_dir),
+            ("AOC_FIXTURE_DIR", &mut self.fixture_dir),
+            ("AOC_ANSWER_DIR", &mut self.answer_dir),
+            ("AOC_LEADERBOARD_DIR", &mut self.leaderboard_dir),
+        ];
+        for (name, dir) in dirs {
+            if let Some(value) = var(name) {
+                *dir = PathBuf::from(value);
+            }
+        }
+        if let Some(sources) = var("AOC_COOKIE_SOURCES") {
+            self.session = sources
+                .split(',')
+                .map(SessionSource::parse)
+                .collect::<Result<_>>()?;
+        }
+        if let Some(timings) = var("AOC_TIMINGS") {
+            self.output.timings = matches!(timings.trim(), "1" | "true" | "yes");
+        }
+        if let Some(format) = var("AOC_FORMAT") {
+            self.output.format = format
+                .parse()
+                .map_err(|_| Error::Config(format!("AOC_FORMAT is not a format: {format}")))?;
+        }
+        Ok(self)
+    }
+
+    /// The first session cookie found in the configured sources.
+    pub fn session_id(&self) -> Option<String> {
+        self.session.iter().find_map(|source| source.lookup())
+    }
+}
+
+// TESTS
+
+#[test]
+fn test_config_from_toml() {
+    assert_eq!(Config::from_toml("").unwrap(), Config::default());
+    let config = Config::from_toml(
+        r#"
+year = 2021
+input_dir = "data/inputs"
+session = ["env:MY_SESSION", "file:/etc/aoc/token"]
+
+[output]
+timings = true
+format = "json"
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.year, 2021);
+    assert_eq!(config.input_dir, PathBuf::from("data/inputs"));
+    assert_eq!(config.fixture_dir, PathBuf::from("./fixtures"));
+    assert_eq!(
+        config.session,
+        vec![
+            SessionSource::Var("MY_SESSION".to_string()),
+            SessionSource::File(PathBuf::from("/etc/aoc/token")),
+        ]
+    );
+    assert!(config.output.timings);
+    assert_eq!(config.output.format, Format::Json);
+    assert!(matches!(
+        Config::from_toml("session = [\"keyring\"]"),
+        Err(Error::Config(_))
+    ));
+    assert!(matches!(
+        Config::from_toml("yaer = 2021"),
+        Err(Error::Config(_))
+    ));
+}
+
+#[test]
+fn test_config_overrides() {
+    let vars = |var: &str| match var {
+        "AOC_YEAR" => Some("2020".to_string()),
+        "AOC_FIXTURE_DIR" => Some("/tmp/fixtures".to_string()),
+        "AOC_TIMINGS" => Some("1".to_string()),
+        "AOC_FORMAT" => Some("markdown".to_string()),
+        "AOC_COOKIE_SOURCES" => Some("env:AOC_COOKIE,file:.cookie".to_string()),
+        _ => None,
+    };
+    let config = Config::default().with_overrides(vars).unwrap();
+    assert_eq!(config.year, 2020);
+    assert_eq!(config.input_dir, PathBuf::from("./inputs"));
+    assert_eq!(config.fixture_dir, PathBuf::from("/tmp/fixtures"));
+    assert!(config.output.timings);
+    assert_eq!(config.output.format, Format::Markdown);
+    assert_eq!(config.session.len(), 2);
+    let bad = |var: &str| (var == "AOC_YEAR").then(|| "next".to_string());
+    assert!(Config::default().with_overrides(bad).is_err());
+}
+
+#[test]
+fn test_session_lookup_order() {
+    let dir = crate::testing::temp_dir("config_session");
+    fs::write(dir.join("token"), "abc123\n").unwrap();
+    let config = Config {
+        session: vec![
+            SessionSource::File(dir.join("missing")),
+            SessionSource::Var("AOC_TEST_UNSET_SESSION".to_string()),
+            SessionSource::File(dir.join("token")),
+        ],
+        ..Config::default()
+    };
+    assert_eq!(config.session_id(), Some("abc123".to_string()));
+}
@@ -0,0 +1,99 @@
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Key, XChaCha20Poly1305, XNonce,
+};
+
+use crate::error::{Error, Result};
+
+/// Marks a cached file as encrypted; plaintext inputs never start with it.
+const MAGIC: &[u8] = b"AOC-ENC1";
+const NONCE_LEN: usize = 24;
+
+/// Key for encrypting cached inputs at rest.
+#[derive(Clone)]
+pub struct CacheKey(Key);
+
+impl CacheKey {
+    /// Parse a key written as 64 hex digits, e.g. the output of
+    /// `openssl rand -hex 32`.
+    pub fn from_hex(hex: &str) -> Result<CacheKey> {
+        let hex = hex.trim();
+        let bad_key = || Error::Crypto("a cache key must be 64 hex digits");
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(bad_key());
+        }
+        let mut key = [0u8; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).map_err(|_| bad_key())?;
+        }
+        Ok(CacheKey(key.into()))
+    }
+
+    /// The key in `var`, either in the environment or in `.env`, if set.
+    pub fn from_env(var: &str) -> Result<Option<CacheKey>> {
+        match dotenv::var(var) {
+            Ok(hex) => Ok(Some(CacheKey::from_hex(&hex)?)),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+pub fn encrypt(key: &CacheKey, plaintext: &[u8]) -> Vec<u8> {
+    let cipher = XChaCha20Poly1305::new(&key.0);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher.encrypt(&nonce, plaintext).unwrap();
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+/// The plaintext of `data`, which is passed through untouched if it was
+/// never encrypted.
+pub fn decrypt(key: Option<&CacheKey>, data: &[u8]) -> Result<Vec<u8>> {
+    if !is_encrypted(data) {
+        return Ok(data.to_vec());
+    }
+    let key = key.ok_or(Error::Crypto(
+        "the input is encrypted but AOC_CACHE_KEY is not set",
+    ))?;
+    let rest = &data[MAGIC.len()..];
+    if rest.len() < NONCE_LEN {
+        return Err(Error::Crypto("the encrypted input is truncated"));
+    }
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    XChaCha20Poly1305::new(&key.0)
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::Crypto("wrong cache key, or the input is corrupted"))
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const TEST_KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+#[test]
+fn test_round_trip() {
+    let key = CacheKey::from_hex(TEST_KEY).unwrap();
+    let sealed = encrypt(&key, b"1000\n2000\n");
+    assert!(is_encrypted(&sealed));
+    assert_ne!(encrypt(&key, b"1000\n2000\n"), sealed);
+    assert_eq!(decrypt(Some(&key), &sealed).unwrap(), b"1000\n2000\n");
+    assert_eq!(decrypt(None, b"1000\n").unwrap(), b"1000\n");
+}
+
+#[test]
+fn test_decrypt_failures() {
+    let key = CacheKey::from_hex(TEST_KEY).unwrap();
+    let other = CacheKey::from_hex(&"ab".repeat(32)).unwrap();
+    let sealed = encrypt(&key, b"1000\n");
+    assert!(matches!(decrypt(None, &sealed), Err(Error::Crypto(_))));
+    assert!(matches!(
+        decrypt(Some(&other), &sealed),
+        Err(Error::Crypto(_))
+    ));
+    assert!(matches!(decrypt(Some(&key), MAGIC), Err(Error::Crypto(_))));
+    assert!(CacheKey::from_hex("abcd").is_err());
+    assert!(CacheKey::from_hex(&"zz".repeat(32)).is_err());
+}
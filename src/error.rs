@@ -0,0 +1,116 @@
+use std::{fmt, io, path::PathBuf};
+
+use reqwest::StatusCode;
+
+/// Everything that can go wrong while talking to the site or its caches.
+#[derive(Debug)]
+pub enum Error {
+    /// None of the configured session sources has a cookie.
+    MissingSession,
+    /// The site no longer recognises the session cookie.
+    SessionExpired,
+    /// The server answered, but not with the input.
+    Http(StatusCode),
+    /// The body came back, but it is an error page rather than a puzzle input.
+    BadContent(&'static str),
+    /// The request never got an answer.
+    Network(reqwest::Error),
+    Io(io::Error),
+    /// The input cache directory is missing and cannot be created.
+    CacheDir(PathBuf, io::Error),
+    /// An answer that should not be sent, and why.
+    Refused(String),
+    /// A reply from the site, or a file saved from one, that cannot be made
+    /// sense of.
+    Parse(&'static str),
+    /// An encrypted input that cannot be read, or a key that cannot be used.
+    Crypto(&'static str),
+    /// `aoc.toml` or one of its overrides cannot be used.
+    Config(String),
+    /// No example was saved for the day, see `Puzzle::save`.
+    MissingExample(usize),
+    /// The command line cannot be made sense of.
+    Args(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Print a one-line diagnosis and exit, for binaries that cannot go on.
+    pub fn exit(&self) -> ! {
+        eprintln!("error: {self}");
+        std::process::exit(1)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found; set AOC_SESSION_ID or list its sources in aoc.toml"
+            ),
+            Error::SessionExpired => write!(
+                f,
+                "the session cookie has expired; log in again and copy the new one"
+            ),
+            Error::Http(status) => write!(f, "server answered with {status}"),
+            Error::BadContent(reason) => write!(f, "refusing to cache input: {reason}"),
+            Error::Network(e) => write!(f, "request failed: {e}"),
+            Error::Io(e) => write!(f, "{e}"),
+            Error::CacheDir(path, e) => {
+                write!(f, "cannot use {} as input cache: {e}", path.display())
+            }
+            Error::Refused(reason) => write!(f, "not submitting: {reason}"),
+            Error::Parse(reason) => write!(f, "{reason}"),
+            Error::Crypto(reason) => write!(f, "{reason}"),
+            Error::Config(reason) => write!(f, "bad configuration: {reason}"),
+            Error::MissingExample(day) => write!(
+                f,
+                "no example saved for day {day}; fetch it with `aoc --puzzle {day}`"
+            ),
+            Error::Args(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Network(e) => Some(e),
+            Error::Io(e) | Error::CacheDir(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Network(e)
+    }
+}
+
+// TESTS
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        Error::Http(StatusCode::NOT_FOUND).to_string(),
+        "server answered with 404 Not Found"
+    );
+    let e = Error::CacheDir(
+        PathBuf::from("./inputs"),
+        io::Error::new(io::ErrorKind::PermissionDenied, "denied"),
+    );
+    assert_eq!(e.to_string(), "cannot use ./inputs as input cache: denied");
+    assert_eq!(
+        Error::Parse("could not make sense of the reply").to_string(),
+        "could not make sense of the reply"
+    );
+}
@@ -0,0 +1,206 @@
+use std::ops::{Index, IndexMut};
+
+/// A square of a grid, as `(x, y)` with `x` to the right and `y` down.
+pub type Point = (i32, i32);
+
+/// Up, down, left and right.
+pub const ORTHOGONAL: [Point; 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// The orthogonal directions and the diagonals.
+pub const ADJACENT: [Point; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangle of cells, stored row by row in one `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from a character map, one line per row, turning each
+    /// character into a cell with `cell`. Panics if the lines are not all as
+    /// long as the first.
+    pub fn parse(input: &str, mut cell: impl FnMut(Point, char) -> T) -> Self {
+        let mut width = None;
+        let mut cells = vec![];
+        let mut height = 0;
+        for (y, line) in (0_i32..).zip(input.lines()) {
+            let before = cells.len();
+            cells.extend((0_i32..).zip(line.chars()).map(|(x, c)| cell((x, y), c)));
+            let len = cells.len() - before;
+            assert_eq!(
+                *width.get_or_insert(len),
+                len,
+                "row {y} of the grid is not as wide as the first"
+            );
+            height += 1;
+        }
+        Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.1 as usize * self.width + point.0 as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height as i32).flat_map(move |y| (0..self.width as i32).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, row by row, whose cell matches `wanted`.
+    pub fn find(&self, wanted: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, cell)| wanted(cell)).map(|(p, _)| p)
+    }
+
+    /// The points in `directions` from `point` that are on the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        (x, y): Point,
+        directions: &'a [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&p| self.contains(p))
+    }
+
+    /// The points above, below, left and right of `point`.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &ORTHOGONAL)
+    }
+
+    /// The points all around `point`, diagonals included.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &ADJACENT)
+    }
+
+    /// The cells met walking from `point` in `direction` to the edge of the
+    /// grid, `point` itself left out.
+    pub fn ray(&self, (x, y): Point, (dx, dy): Point) -> impl Iterator<Item = (Point, &T)> {
+        (1..)
+            .map(move |step| (x + dx * step, y + dy * step))
+            .map_while(|p| self.get(p).map(|cell| (p, cell)))
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: i32) -> impl Iterator<Item = (Point, &T)> {
+        self.ray((-1, y), (1, 0))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = (Point, &T)> {
+        self.ray((x, -1), (0, 1))
+    }
+
+    /// Draw the grid back as text, one line per row, with `cell` choosing
+    /// each character.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            out.extend(row.iter().map(&cell));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is off the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is off the grid"))
+    }
+}
+
+// TESTS
+
+#[test]
+fn test_grid() {
+    let mut grid = Grid::parse("ab.\n.#c\n", |_, c| c);
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 1)], '#');
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.get((0, -1)), None);
+    assert_eq!(grid.find(|&c| c == 'c'), Some((2, 1)));
+
+    let around: Vec<Point> = grid.neighbours4((0, 0)).collect();
+    assert_eq!(around, vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+
+    let ray: String = grid.ray((2, 1), (-1, 0)).map(|(_, &c)| c).collect();
+    assert_eq!(ray, "#.");
+    let row: String = grid.row(0).map(|(_, &c)| c).collect();
+    assert_eq!(row, "ab.");
+    let column: Vec<Point> = grid.column(2).map(|(p, _)| p).collect();
+    assert_eq!(column, vec![(2, 0), (2, 1)]);
+
+    grid[(2, 0)] = '*';
+    assert_eq!(grid.render(|&c| c), "ab*\n.#c\n");
+    assert_eq!(Grid::new(2, 1, 0u8).render(|&n| (b'0' + n) as char), "00\n");
+}
+
+#[test]
+#[should_panic(expected = "not as wide")]
+fn test_grid_ragged() {
+    Grid::parse("ab\nc\n", |_, c| c);
+}
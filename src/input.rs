@@ -0,0 +1,399 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use reqwest::StatusCode;
+
+use crate::{
+    client::AocClient,
+    crypto::{self, CacheKey},
+    error::{Error, Result},
+};
+
+/// Somewhere puzzle inputs can be fetched from.
+pub trait InputSource {
+    fn fetch(&self, day: usize) -> Result<Vec<u8>>;
+}
+
+/// Downloads inputs from the Advent of Code site.
+pub struct HttpSource {
+    client: AocClient,
+}
+
+impl HttpSource {
+    pub fn new(client: AocClient) -> Self {
+        HttpSource { client }
+    }
+
+    pub fn from_env() -> Self {
+        HttpSource::new(AocClient::from_env())
+    }
+}
+
+impl InputSource for HttpSource {
+    fn fetch(&self, day: usize) -> Result<Vec<u8>> {
+        let path = format!("/{}/day/{day}/input", self.client.year());
+        let response = self.client.get(&path)?;
+        Ok(response.bytes()?.to_vec())
+    }
+}
+
+/// Reads inputs from `NN.txt` files in a local directory.
+pub struct DirSource {
+    dir: PathBuf,
+}
+
+impl DirSource {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        DirSource {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl InputSource for DirSource {
+    fn fetch(&self, day: usize) -> Result<Vec<u8>> {
+        Ok(fs::read(self.dir.join(format!("{day:0>2}.txt")))?)
+    }
+}
+
+/// Inputs held in memory, mostly for tests.
+#[derive(Default)]
+pub struct MemorySource {
+    inputs: HashMap<usize, String>,
+}
+
+impl MemorySource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, day: usize, input: &str) -> Self {
+        self.inputs.insert(day, input.to_string());
+        self
+    }
+}
+
+impl InputSource for MemorySource {
+    fn fetch(&self, day: usize) -> Result<Vec<u8>> {
+        match self.inputs.get(&day) {
+            Some(input) => Ok(input.clone().into_bytes()),
+            None => Err(Error::Http(StatusCode::NOT_FOUND)),
+        }
+    }
+}
+
+/// Known bodies the site sends back instead of an input, and why.
+const ERROR_PAGES: [(&str, &str); 3] = [
+    ("Please log in", "the session cookie was not accepted"),
+    (
+        "Please don't repeatedly request this endpoint before it unlocks",
+        "the puzzle has not unlocked yet",
+    ),
+    ("404 Not Found", "there is no such puzzle"),
+];
+
+/// Sanity check a body before it is trusted as a puzzle input.
+pub fn check_content(content: &[u8]) -> Result<()> {
+    let text = String::from_utf8_lossy(content);
+    for (marker, reason) in ERROR_PAGES {
+        if text.contains(marker) {
+            return Err(Error::BadContent(reason));
+        }
+    }
+    let start = text.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return Err(Error::BadContent("got an HTML page"));
+    }
+    if text.trim().is_empty() {
+        return Err(Error::BadContent("got an empty body"));
+    }
+    Ok(())
+}
+
+/// A directory of `NN.txt` inputs, filled from an `InputSource` on demand.
+/// With a key, inputs are encrypted on disk and decrypted as they are read.
+pub struct InputCache<S> {
+    dir: PathBuf,
+    source: S,
+    key: Option<CacheKey>,
+}
+
+impl<S: InputSource> InputCache<S> {
+    pub fn new<P: AsRef<Path>>(dir: P, source: S) -> Self {
+        InputCache {
+            dir: dir.as_ref().to_path_buf(),
+            source,
+            key: None,
+        }
+    }
+
+    pub fn with_key(mut self, key: Option<CacheKey>) -> Self {
+        self.key = key;
+        self
+    }
+
+    pub fn path(&self, day: usize) -> PathBuf {
+        self.dir.join(format!("{day:0>2}.txt"))
+    }
+
+    /// Whether a usable input for `day` is already on disk.
+    pub fn is_cached(&self, day: usize) -> bool {
+        let path = self.path(day);
+        path.exists() && self.load(&path).is_ok_and(|c| check_content(&c).is_ok())
+    }
+
+    /// Path to the cached input for `day`, fetching it first if needed.
+    pub fn get(&self, day: usize) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir).map_err(|e| Error::CacheDir(self.dir.clone(), e))?;
+        let filepath = self.path(day);
+        // Does the input file already exist? Older versions cached error pages
+        // too, so a cached file that fails the checks is fetched again.
+        if filepath.exists() && check_content(&self.load(&filepath)?).is_err() {
+            self.invalidate(day)?;
+        }
+        if !filepath.exists() {
+            let content = self.source.fetch(day)?;
+            check_content(&content)?;
+            self.store(&filepath, &content)?;
+        }
+        Ok(filepath)
+    }
+
+    /// The input for `day`, fetching it first if needed.
+    pub fn read(&self, day: usize) -> Result<String> {
+        let content = self.load(&self.get(day)?)?;
+        String::from_utf8(content).map_err(|_| Error::BadContent("the input is not UTF-8"))
+    }
+
+    fn load(&self, path: &Path) -> Result<Vec<u8>> {
+        crypto::decrypt(self.key.as_ref(), &fs::read(path)?)
+    }
+
+    fn store(&self, path: &Path, content: &[u8]) -> Result<()> {
+        match &self.key {
+            Some(key) => fs::write(path, crypto::encrypt(key, content))?,
+            None => fs::write(path, content)?,
+        }
+        Ok(())
+    }
+
+    /// Rewrite every cached input under `new_key`, or as plaintext if there
+    /// is none. Returns how many files were rewritten.
+    pub fn rekey(&mut self, new_key: Option<CacheKey>) -> Result<usize> {
+        let mut plaintexts = vec![];
+        if !self.dir.exists() {
+            return Ok(0);
+        }
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e == "txt") {
+                let content = self.load(&path)?;
+                plaintexts.push((path, content));
+            }
+        }
+        // Everything is decrypted before anything is rewritten, so a wrong
+        // current key leaves the cache as it was.
+        self.key = new_key;
+        for (path, content) in plaintexts.iter() {
+            self.store(path, content)?;
+        }
+        Ok(plaintexts.len())
+    }
+
+    /// Move the `NN.txt` inputs kept straight in `old_dir`, where they were
+    /// cached before there was a directory per year, into this cache. Days
+    /// already cached here are left where they are. Returns how many inputs
+    /// were moved.
+    pub fn adopt<P: AsRef<Path>>(&self, old_dir: P) -> Result<usize> {
+        let old_dir = old_dir.as_ref();
+        if !old_dir.is_dir() {
+            return Ok(0);
+        }
+        let mut moved = 0;
+        for entry in fs::read_dir(old_dir)? {
+            let path = entry?.path();
+            let day = path
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_suffix(".txt")?.parse::<usize>().ok());
+            let Some(day) = day.filter(|_| path.is_file()) else {
+                continue;
+            };
+            if !self.path(day).exists() {
+                fs::create_dir_all(&self.dir).map_err(|e| Error::CacheDir(self.dir.clone(), e))?;
+                fs::rename(&path, self.path(day))?;
+                moved += 1;
+            }
+        }
+        Ok(moved)
+    }
+
+    /// Drop the cached input for `day`, so the next `get` fetches it again.
+    pub fn invalidate(&self, day: usize) -> Result<()> {
+        match fs::remove_file(self.path(day)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+// TESTS
+
+#[test]
+fn test_check_content() {
+    assert!(check_content(b"1000\n2000\n\n3000\n").is_ok());
+    assert!(matches!(
+        check_content(b"Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+        Err(Error::BadContent("the session cookie was not accepted"))
+    ));
+    assert!(matches!(
+        check_content(b"Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"),
+        Err(Error::BadContent("the puzzle has not unlocked yet"))
+    ));
+    assert!(matches!(
+        check_content(b"<!DOCTYPE html>\n<html lang=\"en-us\">"),
+        Err(Error::BadContent(_))
+    ));
+    assert!(matches!(check_content(b"\n"), Err(Error::BadContent(_))));
+}
+
+#[test]
+fn test_input_cache_memory() {
+    let dir = crate::testing::temp_dir("input_cache_memory");
+    let cache = InputCache::new(&dir, MemorySource::new().with(1, "1000\n2000\n"));
+    let path = cache.get(1).unwrap();
+    assert_eq!(path, dir.join("01.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+    assert!(matches!(
+        cache.get(2),
+        Err(Error::Http(StatusCode::NOT_FOUND))
+    ));
+    assert!(!cache.path(2).exists());
+}
+
+#[test]
+fn test_input_cache_replaces_bad_file() {
+    let dir = crate::testing::temp_dir("input_cache_bad_file");
+    fs::write(
+        dir.join("03.txt"),
+        "Please log in to get your puzzle input.",
+    )
+    .unwrap();
+    let cache = InputCache::new(
+        &dir,
+        MemorySource::new().with(3, "vJrwpWtwJgWrhcsFMMfFFhFp\n"),
+    );
+    assert_eq!(
+        fs::read_to_string(cache.get(3).unwrap()).unwrap(),
+        "vJrwpWtwJgWrhcsFMMfFFhFp\n"
+    );
+    cache.invalidate(3).unwrap();
+    assert!(!cache.path(3).exists());
+    cache.invalidate(3).unwrap();
+}
+
+#[test]
+fn test_input_cache_adopt() {
+    let root = crate::testing::temp_dir("input_cache_adopt");
+    fs::write(root.join("01.txt"), "1000\n").unwrap();
+    fs::write(root.join("02.txt"), "A Y\n").unwrap();
+    fs::write(root.join("notes.txt"), "not an input").unwrap();
+    let cache = InputCache::new(root.join("2022"), MemorySource::new());
+    fs::create_dir_all(root.join("2022")).unwrap();
+    fs::write(cache.path(2), "B X\n").unwrap();
+
+    assert_eq!(cache.adopt(&root).unwrap(), 1);
+    // Read from the old file, not fetched, as the source has nothing.
+    assert_eq!(cache.read(1).unwrap(), "1000\n");
+    assert!(!root.join("01.txt").exists());
+    // Already cached in the new place, so the old one is left alone.
+    assert_eq!(cache.read(2).unwrap(), "B X\n");
+    assert!(root.join("02.txt").exists() && root.join("notes.txt").exists());
+    assert_eq!(cache.adopt(root.join("missing")).unwrap(), 0);
+}
+
+#[test]
+fn test_dir_source() {
+    let src = crate::testing::temp_dir("input_dir_source");
+    fs::write(src.join("04.txt"), "2-4,6-8\n").unwrap();
+    let cache = InputCache::new(
+        crate::testing::temp_dir("input_dir_cache"),
+        DirSource::new(&src),
+    );
+    assert_eq!(
+        fs::read_to_string(cache.get(4).unwrap()).unwrap(),
+        "2-4,6-8\n"
+    );
+    assert!(matches!(cache.get(5), Err(Error::Io(_))));
+}
+
+#[test]
+fn test_http_source() {
+    let server = crate::testing::Server::start(vec![
+        (200, "1000\n2000\n"),
+        (400, "Please log in to get your puzzle input.\n"),
+        (
+            200,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        ),
+    ]);
+    let dir = crate::testing::temp_dir("input_http");
+    let client = AocClient::new(Some("abc123".to_string()))
+        .with_base_url(&server.url)
+        .with_contact("someone@example.com");
+    let source = HttpSource::new(client);
+    let cache = InputCache::new(&dir, source);
+
+    assert_eq!(
+        fs::read_to_string(cache.get(1).unwrap()).unwrap(),
+        "1000\n2000\n"
+    );
+    assert!(server.requests()[0].contains("user-agent: aoc2022/0.1.0 (someone@example.com)"));
+    // Cached now, so this must not reach the server.
+    cache.get(1).unwrap();
+    assert!(matches!(
+        cache.get(2),
+        Err(Error::Http(StatusCode::BAD_REQUEST))
+    ));
+    assert!(matches!(cache.get(3), Err(Error::BadContent(_))));
+    assert!(!cache.path(2).exists() && !cache.path(3).exists());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("GET /2022/day/1/input "));
+    assert!(requests[0].contains("cookie: session=abc123"));
+    assert!(requests[2].starts_with("GET /2022/day/3/input "));
+}
+
+#[test]
+fn test_http_source_without_session() {
+    let source = HttpSource::new(AocClient::new(None).with_base_url("http://127.0.0.1:9"));
+    assert!(matches!(source.fetch(1), Err(Error::MissingSession)));
+}
+
+#[test]
+fn test_encrypted_cache() {
+    let dir = crate::testing::temp_dir("input_encrypted");
+    let key = CacheKey::from_hex(&"42".repeat(32)).unwrap();
+    let source = MemorySource::new().with(1, "1000\n").with(2, "A Y\n");
+    let mut cache = InputCache::new(&dir, source).with_key(Some(key.clone()));
+    assert_eq!(cache.read(1).unwrap(), "1000\n");
+    assert!(crypto::is_encrypted(&fs::read(cache.path(1)).unwrap()));
+    fs::write(cache.path(2), "A Y\n").unwrap();
+    // A plaintext file left over from before encryption still reads fine.
+    assert_eq!(cache.read(2).unwrap(), "A Y\n");
+
+    let other = CacheKey::from_hex(&"17".repeat(32)).unwrap();
+    assert_eq!(cache.rekey(Some(other.clone())).unwrap(), 2);
+    assert!(crypto::is_encrypted(&fs::read(cache.path(2)).unwrap()));
+    let wrong = InputCache::new(&dir, MemorySource::new()).with_key(Some(key));
+    assert!(matches!(wrong.read(1), Err(Error::Crypto(_))));
+    let unkeyed = InputCache::new(&dir, MemorySource::new());
+    assert!(matches!(unkeyed.read(1), Err(Error::Crypto(_))));
+
+    assert_eq!(cache.rekey(None).unwrap(), 2);
+    assert_eq!(fs::read_to_string(cache.path(1)).unwrap(), "1000\n");
+    assert_eq!(unkeyed.read(2).unwrap(), "A Y\n");
+}
@@ -0,0 +1,220 @@
+use std::cmp::{max, min};
+
+/// The whole numbers from `start` to `end`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// How many numbers it holds; none if `end` comes before `start`.
+    pub fn len(&self) -> i64 {
+        max(self.end - self.start + 1, 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    /// Whether every number of `other` is in this one too.
+    pub fn covers(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let both = Interval::new(max(self.start, other.start), min(self.end, other.end));
+        (!both.is_empty()).then_some(both)
+    }
+}
+
+/// A set of whole numbers kept as sorted intervals that neither overlap nor
+/// touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The intervals, lowest first.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many numbers are in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let i = self.intervals.partition_point(|iv| iv.end < x);
+        self.intervals.get(i).is_some_and(|iv| iv.contains(x))
+    }
+
+    /// Add `interval`, merging it with any it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // The intervals from `first` up to `last` (excluded) are the ones to
+        // merge with.
+        let first = self
+            .intervals
+            .partition_point(|iv| iv.end < interval.start - 1);
+        let last = self
+            .intervals
+            .partition_point(|iv| iv.start <= interval.end + 1);
+        let mut merged = interval;
+        if first < last {
+            merged.start = min(merged.start, self.intervals[first].start);
+            merged.end = max(merged.end, self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Take every number of `interval` out of the set.
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|iv| iv.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|iv| iv.start <= interval.end);
+        if first == last {
+            return;
+        }
+        let left = Interval::new(self.intervals[first].start, interval.start - 1);
+        let right = Interval::new(interval.end + 1, self.intervals[last - 1].end);
+        let kept = [left, right].into_iter().filter(|iv| !iv.is_empty());
+        self.intervals.splice(first..last, kept);
+    }
+
+    pub fn remove_point(&mut self, x: i64) {
+        self.remove(Interval::new(x, x))
+    }
+
+    /// Drop everything outside `limits`.
+    pub fn clip(&mut self, limits: Interval) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .filter_map(|iv| iv.intersection(&limits))
+            .collect();
+    }
+
+    /// The stretches of `within` that are not in the set, lowest first.
+    pub fn gaps(&self, within: Interval) -> Vec<Interval> {
+        let mut gaps = vec![];
+        let mut next = within.start;
+        for iv in self.intervals.iter() {
+            if iv.end < next {
+                continue;
+            }
+            if iv.start > within.end {
+                break;
+            }
+            if iv.start > next {
+                gaps.push(Interval::new(next, iv.start - 1));
+            }
+            next = iv.end + 1;
+        }
+        if next <= within.end {
+            gaps.push(Interval::new(next, within.end));
+        }
+        gaps
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+// TESTS
+
+#[test]
+fn test_interval() {
+    let a = Interval::new(2, 6);
+    assert_eq!(a.len(), 5);
+    assert_eq!(Interval::new(3, 2).len(), 0);
+    assert!(a.covers(&Interval::new(3, 6)) && !a.covers(&Interval::new(3, 7)));
+    assert!(a.overlaps(&Interval::new(6, 9)) && !a.overlaps(&Interval::new(7, 9)));
+    assert_eq!(
+        a.intersection(&Interval::new(4, 8)),
+        Some(Interval::new(4, 6))
+    );
+}
+
+#[test]
+fn test_interval_set() {
+    let mut set: IntervalSet = [(12, 12), (2, 4), (-2, 2), (14, 18), (5, 5)]
+        .into_iter()
+        .map(|(start, end)| Interval::new(start, end))
+        .collect();
+    // 5 touches 2..=4, so they join up; 12 and 14 do not touch.
+    assert_eq!(
+        set.intervals(),
+        [
+            Interval::new(-2, 5),
+            Interval::new(12, 12),
+            Interval::new(14, 18)
+        ]
+    );
+    assert_eq!(set.len(), 14);
+    assert!(set.contains(0) && !set.contains(13));
+
+    set.insert(Interval::new(6, 13));
+    assert_eq!(set.intervals(), [Interval::new(-2, 18)]);
+
+    set.remove_point(2);
+    set.remove(Interval::new(10, 15));
+    set.remove(Interval::new(30, 40));
+    assert_eq!(
+        set.intervals(),
+        [
+            Interval::new(-2, 1),
+            Interval::new(3, 9),
+            Interval::new(16, 18)
+        ]
+    );
+    assert_eq!(
+        set.gaps(Interval::new(0, 20)),
+        [
+            Interval::new(2, 2),
+            Interval::new(10, 15),
+            Interval::new(19, 20)
+        ]
+    );
+    assert!(set.gaps(Interval::new(4, 8)).is_empty());
+
+    set.clip(Interval::new(0, 17));
+    assert_eq!(set.len(), 2 + 7 + 2);
+    set.remove(Interval::new(-5, 50));
+    assert!(set.is_empty());
+}
@@ -0,0 +1,317 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    client::AocClient,
+    error::{Error, Result},
+    schedule::unlock_time,
+};
+
+/// The site asks that private leaderboards are fetched at most this often.
+pub const REFRESH: Duration = Duration::from_secs(15 * 60);
+
+/// When one star was earned.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Stars by day, then by part.
+    pub completion_day_level: BTreeMap<usize, BTreeMap<u8, Star>>,
+}
+
+impl Member {
+    /// The name, or what the site shows in its place.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// How long after `day` of `year` unlocked this member got `part`'s
+    /// star, if at all.
+    pub fn solve_time(&self, year: u16, day: usize, part: u8) -> Option<Duration> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        let at = UNIX_EPOCH + Duration::from_secs(star.get_star_ts);
+        Some(
+            at.duration_since(unlock_time(year, day))
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// A private leaderboard as the site serves it in JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> Result<Leaderboard> {
+        let leaderboard: Leaderboard = serde_json::from_str(json)
+            .map_err(|_| Error::Parse("the leaderboard is not valid JSON"))?;
+        match leaderboard.event.parse::<u16>() {
+            Ok(year) if year >= 2015 => Ok(leaderboard),
+            _ => Err(Error::Parse("the leaderboard is not for a known year")),
+        }
+    }
+
+    /// The year of the event, checked to be one when parsed.
+    pub fn year(&self) -> u16 {
+        self.event.parse().unwrap()
+    }
+
+    /// Members by local score, best first.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            (b.local_score, b.stars)
+                .cmp(&(a.local_score, a.stars))
+                .then_with(|| a.display_name().cmp(&b.display_name()))
+        });
+        members
+    }
+
+    /// The last day anyone has a star for.
+    fn last_day(&self) -> usize {
+        self.members
+            .values()
+            .filter_map(|m| m.completion_day_level.keys().max())
+            .max()
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Overall standings, with a `*` for each day a member finished, a `+`
+    /// for each day they only got part 1 of, and a `.` for the rest.
+    pub fn render_standings(&self) -> String {
+        let rows = self
+            .standings()
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let days: String = (1..=self.last_day())
+                    .map(|day| match m.completion_day_level.get(&day) {
+                        Some(parts) if parts.len() == 2 => '*',
+                        Some(_) => '+',
+                        None => '.',
+                    })
+                    .collect();
+                vec![
+                    format!("{})", i + 1),
+                    m.local_score.to_string(),
+                    m.stars.to_string(),
+                    days,
+                    m.display_name(),
+                ]
+            })
+            .collect();
+        table(&["", "Score", "Stars", "Days", "Name"], rows)
+    }
+
+    /// How long everyone with a star on `day` took for each part, and
+    /// between them, fastest first.
+    pub fn render_day(&self, day: usize) -> String {
+        let year = self.year();
+        let mut members: Vec<&Member> = self
+            .members
+            .values()
+            .filter(|m| m.solve_time(year, day, 1).is_some())
+            .collect();
+        members.sort_by_key(|m| {
+            (
+                m.solve_time(year, day, 2).unwrap_or(Duration::MAX),
+                m.solve_time(year, day, 1),
+            )
+        });
+        let rows = members
+            .iter()
+            .map(|m| {
+                let part1 = m.solve_time(year, day, 1);
+                let part2 = m.solve_time(year, day, 2);
+                let delta = part1.zip(part2).map(|(p1, p2)| p2.saturating_sub(p1));
+                vec![hms(part1), hms(part2), hms(delta), m.display_name()]
+            })
+            .collect();
+        table(&["Part 1", "Part 2", "Delta", "Name"], rows)
+    }
+}
+
+fn hms(duration: Option<Duration>) -> String {
+    match duration {
+        Some(d) => {
+            let s = d.as_secs();
+            format!("{}:{:0>2}:{:0>2}", s / 3600, s / 60 % 60, s % 60)
+        }
+        None => "-".to_string(),
+    }
+}
+
+/// Lay `rows` out in columns under `header`. Every column but the last is
+/// right-aligned.
+fn table(header: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let header: Vec<String> = header.iter().map(|h| h.to_string()).collect();
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let last = row.len() - 1;
+        let cells: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                if i == last {
+                    cell.clone()
+                } else {
+                    format!("{cell:>width$}", width = widths[i])
+                }
+            })
+            .collect();
+        out.push_str(cells.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// Private leaderboard `id`, from the copy in `dir` if it was fetched less
+/// than `REFRESH` before `now`, and from the site otherwise.
+pub fn fetch_leaderboard(
+    client: &AocClient,
+    dir: &Path,
+    id: u64,
+    now: SystemTime,
+) -> Result<Leaderboard> {
+    let path = dir.join(format!("{id}.json"));
+    let fresh = fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .is_ok_and(|fetched| {
+            now.duration_since(fetched)
+                .map_or(true, |age| age < REFRESH)
+        });
+    if fresh {
+        return Leaderboard::from_json(&fs::read_to_string(&path)?);
+    }
+    let json = client
+        .get(&format!(
+            "/{}/leaderboard/private/view/{id}.json",
+            client.year()
+        ))?
+        .text()?;
+    let leaderboard = Leaderboard::from_json(&json)?;
+    fs::create_dir_all(dir).map_err(|e| Error::CacheDir(dir.to_path_buf(), e))?;
+    fs::write(&path, json)?;
+    Ok(leaderboard)
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const LEADERBOARD: &str = r#"{
+  "owner_id": 1001,
+  "event": "2022",
+  "members": {
+    "1001": {
+      "id": 1001, "name": "Alice", "stars": 4, "local_score": 10, "global_score": 0,
+      "last_star_ts": 1669958100,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1669871100, "star_index": 10},
+              "2": {"get_star_ts": 1669871400, "star_index": 12}},
+        "2": {"1": {"get_star_ts": 1669957500, "star_index": 40},
+              "2": {"get_star_ts": 1669958100, "star_index": 41}}
+      }
+    },
+    "1002": {
+      "id": 1002, "name": "Bob", "stars": 3, "local_score": 7, "global_score": 0,
+      "last_star_ts": 1669960800,
+      "completion_day_level": {
+        "1": {"1": {"get_star_ts": 1669870980, "star_index": 5},
+              "2": {"get_star_ts": 1669872000, "star_index": 15}},
+        "2": {"1": {"get_star_ts": 1669960800, "star_index": 50}}
+      }
+    },
+    "1003": {
+      "id": 1003, "name": null, "stars": 0, "local_score": 0, "global_score": 0,
+      "last_star_ts": 0, "completion_day_level": {}
+    }
+  }
+}"#;
+
+#[test]
+fn test_leaderboard_from_json() {
+    let leaderboard = Leaderboard::from_json(LEADERBOARD).unwrap();
+    assert_eq!(leaderboard.members.len(), 3);
+    let names: Vec<String> = leaderboard
+        .standings()
+        .iter()
+        .map(|m| m.display_name())
+        .collect();
+    assert_eq!(names, vec!["Alice", "Bob", "(anonymous user #1003)"]);
+    let bob = &leaderboard.members["1002"];
+    assert_eq!(bob.solve_time(2022, 1, 1), Some(Duration::from_secs(180)));
+    assert_eq!(bob.solve_time(2022, 2, 1), Some(Duration::from_secs(3600)));
+    assert_eq!(bob.solve_time(2022, 2, 2), None);
+    assert!(Leaderboard::from_json("<html>log in</html>").is_err());
+}
+
+#[test]
+fn test_render_leaderboard() {
+    let leaderboard = Leaderboard::from_json(LEADERBOARD).unwrap();
+    assert_eq!(
+        leaderboard.render_standings(),
+        "    Score  Stars  Days  Name
+1)     10      4    **  Alice
+2)      7      3    *+  Bob
+3)      0      0    ..  (anonymous user #1003)
+"
+    );
+    assert_eq!(
+        leaderboard.render_day(1),
+        " Part 1   Part 2    Delta  Name
+0:05:00  0:10:00  0:05:00  Alice
+0:03:00  0:20:00  0:17:00  Bob
+"
+    );
+    assert_eq!(
+        leaderboard.render_day(2),
+        " Part 1   Part 2    Delta  Name
+0:05:00  0:15:00  0:10:00  Alice
+1:00:00        -        -  Bob
+"
+    );
+}
+
+#[test]
+fn test_fetch_leaderboard() {
+    let server = crate::testing::Server::start(vec![(200, LEADERBOARD), (200, LEADERBOARD)]);
+    let client = AocClient::new(Some("abc123".to_string())).with_base_url(&server.url);
+    let dir = crate::testing::temp_dir("leaderboard");
+    let now = SystemTime::now();
+
+    fetch_leaderboard(&client, &dir, 1001, now).unwrap();
+    assert!(server.requests()[0].starts_with("GET /2022/leaderboard/private/view/1001.json "));
+    // Served from the cache until it is 15 minutes old.
+    fetch_leaderboard(&client, &dir, 1001, now + Duration::from_secs(14 * 60)).unwrap();
+    assert_eq!(server.requests().len(), 1);
+    fetch_leaderboard(&client, &dir, 1001, now + Duration::from_secs(16 * 60)).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
@@ -0,0 +1,167 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    submit::Outcome,
+};
+
+/// One answer the site has passed judgement on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Everything submitted for one day, kept as `NN.json` so wrong answers are
+/// never sent twice.
+pub struct Ledger {
+    dir: PathBuf,
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    pub fn open<P: AsRef<Path>>(dir: P, day: usize) -> Result<Ledger> {
+        let dir = dir.as_ref().to_path_buf();
+        let path = dir.join(format!("{day:0>2}.json"));
+        let entries = match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|_| Error::Parse("the answer ledger is not valid JSON"))?,
+            Err(_) => vec![],
+        };
+        Ok(Ledger { dir, path, entries })
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// The answer the site accepted for `part`, if it has been solved.
+    pub fn accepted(&self, part: u8) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.part == part && e.outcome == Outcome::Correct)
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Exclusive bounds on `part`'s answer learned from "too low" and "too
+    /// high" replies.
+    pub fn bounds(&self, part: u8) -> (Option<i64>, Option<i64>) {
+        let mut low = None;
+        let mut high = None;
+        for e in self.entries.iter().filter(|e| e.part == part) {
+            let Ok(value) = e.answer.parse::<i64>() else {
+                continue;
+            };
+            match e.outcome {
+                Outcome::TooLow => low = low.max(Some(value)),
+                Outcome::TooHigh => high = Some(high.map_or(value, |h: i64| h.min(value))),
+                _ => (),
+            }
+        }
+        (low, high)
+    }
+
+    /// Refuse `answer` for `part` if the site has already judged it, or
+    /// something that rules it out.
+    pub fn check(&self, part: u8, answer: &str) -> Result<()> {
+        if let Some(accepted) = self.accepted(part) {
+            return Err(Error::Refused(format!(
+                "part {part} is already solved, the accepted answer is {accepted}"
+            )));
+        }
+        if let Some(e) = self
+            .entries
+            .iter()
+            .find(|e| e.part == part && e.answer == answer)
+        {
+            return Err(Error::Refused(format!(
+                "{answer} was already submitted and was {}",
+                e.outcome
+            )));
+        }
+        if let Ok(value) = answer.parse::<i64>() {
+            match self.bounds(part) {
+                (Some(low), _) if value <= low => {
+                    return Err(Error::Refused(format!(
+                        "{answer} is too low, {low} already was"
+                    )));
+                }
+                (_, Some(high)) if value >= high => {
+                    return Err(Error::Refused(format!(
+                        "{answer} is too high, {high} already was"
+                    )));
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Remember the site's verdict on `answer`. Replies that say nothing
+    /// about the answer itself, like rate limiting, are not kept.
+    pub fn record(&mut self, part: u8, answer: &str, outcome: &Outcome) -> Result<()> {
+        if matches!(outcome, Outcome::RateLimited(_) | Outcome::AlreadySolved) {
+            return Ok(());
+        }
+        self.entries.push(Entry {
+            part,
+            answer: answer.to_string(),
+            outcome: outcome.clone(),
+        });
+        let json = serde_json::to_string_pretty(&self.entries).unwrap();
+        fs::create_dir_all(&self.dir).map_err(|e| Error::CacheDir(self.dir.clone(), e))?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+// TESTS
+
+#[test]
+fn test_ledger() {
+    let dir = crate::testing::temp_dir("ledger");
+    let mut ledger = Ledger::open(&dir, 15).unwrap();
+    assert!(ledger.check(2, "56000011").is_ok());
+    ledger.record(2, "56000011", &Outcome::TooLow).unwrap();
+    ledger.record(2, "99999999", &Outcome::TooHigh).unwrap();
+    ledger.record(2, "70000000", &Outcome::Wrong).unwrap();
+    ledger
+        .record(
+            2,
+            "1",
+            &Outcome::RateLimited(std::time::Duration::from_secs(5)),
+        )
+        .unwrap();
+
+    // Reopen to make sure it all made it to disk.
+    let mut ledger = Ledger::open(&dir, 15).unwrap();
+    assert_eq!(ledger.entries().len(), 3);
+    assert_eq!(ledger.bounds(2), (Some(56000011), Some(99999999)));
+    assert_eq!(ledger.bounds(1), (None, None));
+    assert!(matches!(
+        ledger.check(2, "70000000"),
+        Err(Error::Refused(_))
+    ));
+    assert!(matches!(ledger.check(2, "123"), Err(Error::Refused(_))));
+    assert!(matches!(
+        ledger.check(2, "100000000"),
+        Err(Error::Refused(_))
+    ));
+    assert!(ledger.check(2, "80000000").is_ok());
+    assert!(ledger.check(1, "123").is_ok());
+
+    ledger.record(2, "80000000", &Outcome::Correct).unwrap();
+    assert_eq!(ledger.accepted(2), Some("80000000"));
+    assert_eq!(ledger.accepted(1), None);
+    assert!(matches!(
+        ledger.check(2, "80000001"),
+        Err(Error::Refused(_))
+    ));
+}
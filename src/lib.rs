@@ -1,42 +1,236 @@
-use dotenv;
-use reqwest::header::COOKIE;
+pub mod account;
+pub mod args;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod crypto;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod leaderboard;
+pub mod ledger;
+pub mod prefetch;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
+pub mod run;
+pub mod schedule;
+pub mod search;
+pub mod solution;
+pub mod submit;
+#[cfg(test)]
+mod testing;
+pub mod y2022;
+
+pub use error::{Error, Result};
+pub use solution::{Answer, Solution};
+pub use y2022::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day15,
+    day16, day19, day20,
+};
+
 use std::{
-    fs::File,
-    io::{self, BufRead, Write},
-    path::Path,
+    fs::{self, File},
+    io::{self, BufRead},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::{Duration, SystemTime},
 };
 
-fn get_input(day: usize, session_id: &String) -> Result<bytes::Bytes, reqwest::Error> {
-    let endpoint = format!("https://adventofcode.com/2022/day/{day}/input");
-    let client = reqwest::blocking::Client::new();
-    let content = client
-        .get(endpoint)
-        .header(COOKIE, format!("session={session_id}"))
-        .send()?
-        .bytes()?;
-    Ok(content)
-}
-
-fn save_input(day: usize, filepath: &str, session_id: Option<String>) -> Result<(), io::Error> {
-    let session_id = match session_id {
-        Some(s) => s,
-        None => dotenv::var("AOC_SESSION_ID").expect("Session ID not defined."),
-    };
-    let content =
-        get_input(day, &session_id).expect(format!("Cannot get input for day {day}").as_str());
-    let mut f = File::create(filepath)?;
-    f.write(&content)?;
-    return Ok(());
-}
-
-pub fn check_or_get_input(day: usize) -> String {
-    let filepath = format!("./inputs/{day:0>2}.txt");
-    // Does the input file already exist?
-    if !Path::new(&filepath).exists() {
-        save_input(day, filepath.as_str(), None)
-            .expect(format!("Could not save input for day {day}").as_str());
+use account::Account;
+use client::{AocClient, Session};
+use config::Config;
+use crypto::CacheKey;
+use input::{HttpSource, InputCache};
+use leaderboard::Leaderboard;
+use ledger::Ledger;
+use prefetch::{Prefetch, Prefetched};
+use puzzle::Puzzle;
+use registry::Registry;
+use schedule::SystemClock;
+use submit::Outcome;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// The project configuration, loaded from `aoc.toml` and the environment the
+/// first time it is needed and shared by everything after that.
+pub fn config() -> Result<&'static Config> {
+    if let Some(config) = CONFIG.get() {
+        return Ok(config);
+    }
+    let config = Config::load(config::CONFIG_FILE)?;
+    Ok(CONFIG.get_or_init(|| config))
+}
+
+/// Every solved day, of every year.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    y2022::register(&mut registry);
+    registry
+}
+
+fn client(year: u16) -> Result<AocClient> {
+    Ok(AocClient::from_config(config()?).with_year(year))
+}
+
+/// Where `year`'s files go under one of the configured directories.
+fn year_dir(root: &Path, year: u16) -> PathBuf {
+    root.join(year.to_string())
+}
+
+/// Inputs are cached under `inputs/<year>`, and encrypted on disk when
+/// `AOC_CACHE_KEY` is set. Inputs for the configured year cached straight
+/// in `inputs`, as they were before, are moved over first.
+fn input_cache(year: u16) -> Result<InputCache<HttpSource>> {
+    let config = config()?;
+    let key = CacheKey::from_env("AOC_CACHE_KEY")?;
+    let dir = year_dir(&config.input_dir, year);
+    let cache = InputCache::new(dir, HttpSource::new(client(year)?)).with_key(key);
+    if year == config.year {
+        cache.adopt(&config.input_dir)?;
+    }
+    Ok(cache)
+}
+
+/// Like `input_cache`, but for a named account, under
+/// `inputs/<year>/<account>`, moving over what was cached in
+/// `inputs/<account>` before.
+fn account_cache(account: &Account, year: u16) -> Result<InputCache<HttpSource>> {
+    let config = config()?;
+    let key = CacheKey::from_env("AOC_CACHE_KEY")?;
+    let source = HttpSource::new(account.client(config).with_year(year));
+    let dir = account.dir(year_dir(&config.input_dir, year));
+    let cache = InputCache::new(dir, source).with_key(key);
+    if year == config.year {
+        cache.adopt(account.dir(&config.input_dir))?;
+    }
+    Ok(cache)
+}
+
+/// Make sure the input for `day` of `year` is cached, and say where. With
+/// `AOC_CACHE_KEY` set the file holds ciphertext, so use `read_input` to get
+/// at the input itself.
+pub fn check_or_get_input(year: u16, day: usize) -> Result<String> {
+    Ok(input_cache(year)?.get(day)?.display().to_string())
+}
+
+/// The input for `day` of `year`, fetched if it is not cached yet and
+/// decrypted if it is cached encrypted.
+pub fn read_input(year: u16, day: usize) -> Result<String> {
+    input_cache(year)?.read(day)
+}
+
+/// The input `account` was given for `day` of `year`.
+pub fn read_account_input(account: &Account, year: u16, day: usize) -> Result<String> {
+    account_cache(account, year)?.read(day)
+}
+
+/// Re-encrypt every cached input, for every year and account, with
+/// `new_key`, or decrypt them all if it is `None`. Returns how many inputs
+/// were rewritten.
+pub fn rekey_inputs(new_key: Option<CacheKey>) -> Result<usize> {
+    let config = config()?;
+    let dir = &config.input_dir;
+    // The configured year always, so inputs still cached the old way get
+    // moved into its directory and rekeyed with the rest.
+    let mut years = vec![config.year];
+    if dir.exists() {
+        for entry in fs::read_dir(dir)? {
+            if let Some(year) = entry?.file_name().to_str().and_then(|n| n.parse().ok()) {
+                if !years.contains(&year) {
+                    years.push(year);
+                }
+            }
+        }
     }
-    filepath
+    let mut count = 0;
+    for year in years {
+        count += input_cache(year)?.rekey(new_key.clone())?;
+        for account in account::accounts() {
+            count += account_cache(&account, year)?.rekey(new_key.clone())?;
+        }
+    }
+    Ok(count)
+}
+
+/// Fetch the inputs for `days` of `year` that are unlocked but not cached
+/// yet, a few at a time. The site asks for contact details from anything
+/// that sends it requests in bulk, so this refuses to start without
+/// `AOC_CONTACT`.
+pub fn prefetch_inputs(year: u16, days: &[usize]) -> Result<Vec<(usize, Result<Prefetched>)>> {
+    if dotenv::var("AOC_CONTACT").is_err() {
+        return Err(Error::Config(
+            "set AOC_CONTACT to an email address or repository URL before prefetching".to_string(),
+        ));
+    }
+    Ok(Prefetch::new().run(&input_cache(year)?, year, days, SystemTime::now()))
+}
+
+/// Wait for `day` of `year` to unlock, calling `tick` with the time left
+/// every second, then fetch its input right away.
+pub fn fetch_input_at_unlock(year: u16, day: usize, tick: impl FnMut(Duration)) -> Result<()> {
+    schedule::fetch_at_unlock(&input_cache(year)?, &SystemClock, year, day, tick)?;
+    Ok(())
+}
+
+/// Drop the cached input for `day` of `year`, so the next `read_input`
+/// fetches it again.
+pub fn invalidate_input(year: u16, day: usize) -> Result<()> {
+    input_cache(year)?.invalidate(day)
+}
+
+pub fn invalidate_account_input(account: &Account, year: u16, day: usize) -> Result<()> {
+    account_cache(account, year)?.invalidate(day)
+}
+
+/// Answers submitted so far for `day` of `year`, kept next to the inputs.
+pub fn answer_ledger(year: u16, day: usize) -> Result<Ledger> {
+    Ledger::open(year_dir(&config()?.answer_dir, year), day)
+}
+
+/// Answers submitted so far for `day` of `year` with `account`'s session.
+pub fn account_ledger(account: &Account, year: u16, day: usize) -> Result<Ledger> {
+    Ledger::open(account.dir(year_dir(&config()?.answer_dir, year)), day)
+}
+
+/// Submit `answer` for `part` (1 or 2) of `day` of `year`, with the same
+/// session the inputs are fetched with. Answers the ledger already rules out
+/// are refused without asking the site.
+pub fn submit_answer(year: u16, day: usize, part: u8, answer: &Answer) -> Result<Outcome> {
+    let mut ledger = answer_ledger(year, day)?;
+    submit::submit_recorded(&client(year)?, &mut ledger, day, part, answer)
+}
+
+/// Whom the site takes the configured session, or `account`'s session, to
+/// belong to.
+pub fn validate_session(account: Option<&Account>) -> Result<Session> {
+    let config = config()?;
+    match account {
+        Some(account) => account.client(config).validate_session(),
+        None => client(config.year)?.validate_session(),
+    }
+}
+
+/// Private leaderboard `id` for `year`, cached in the leaderboard directory
+/// so the site is asked at most every 15 minutes.
+pub fn get_leaderboard(year: u16, id: u64) -> Result<Leaderboard> {
+    let dir = year_dir(&config()?.leaderboard_dir, year);
+    leaderboard::fetch_leaderboard(&client(year)?, &dir, id, SystemTime::now())
+}
+
+/// Download the puzzle page for `day` of `year` and save its description
+/// and examples as fixtures.
+pub fn get_puzzle(year: u16, day: usize) -> Result<Puzzle> {
+    let puzzle = puzzle::fetch_puzzle(&client(year)?, day)?;
+    puzzle.save(year_dir(&config()?.fixture_dir, year))?;
+    Ok(puzzle)
+}
+
+/// The first example quoted in the puzzle for `day` of `year`, saved by
+/// `get_puzzle`.
+pub fn read_example(year: u16, day: usize) -> Result<String> {
+    puzzle::read_example(year_dir(&config()?.fixture_dir, year), day, 1)
 }
 
 pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
@@ -0,0 +1,212 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use reqwest::StatusCode;
+
+use crate::{
+    error::{Error, Result},
+    input::{InputCache, InputSource},
+    schedule::is_unlocked,
+};
+
+/// What prefetching did for one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prefetched {
+    Fetched,
+    /// Already cached, so the site was not asked.
+    Cached,
+    /// Not unlocked yet, so the site was not asked.
+    Locked,
+}
+
+/// Fetches many inputs at once, without asking the site for more than a
+/// few requests a second.
+pub struct Prefetch {
+    workers: usize,
+    interval: Duration,
+    retries: u32,
+    backoff: Duration,
+}
+
+impl Default for Prefetch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Prefetch {
+    /// Four workers, one request a second between them, and three retries
+    /// starting two seconds apart.
+    pub fn new() -> Self {
+        Prefetch {
+            workers: 4,
+            interval: Duration::from_secs(1),
+            retries: 3,
+            backoff: Duration::from_secs(2),
+        }
+    }
+
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Start at most `per_second` requests a second, across all workers.
+    pub fn with_rate(mut self, per_second: f64) -> Self {
+        self.interval = Duration::from_secs_f64(1.0 / per_second);
+        self
+    }
+
+    /// Try failures that may be temporary `retries` more times, waiting
+    /// `backoff` before the first retry and twice as long before each next.
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Fill `cache` with the inputs for `days` of `year`, skipping those
+    /// already cached or not unlocked at `now`. Results come back in day
+    /// order.
+    pub fn run<S: InputSource + Sync>(
+        &self,
+        cache: &InputCache<S>,
+        year: u16,
+        days: &[usize],
+        now: SystemTime,
+    ) -> Vec<(usize, Result<Prefetched>)> {
+        let mut results = vec![];
+        let mut missing = vec![];
+        for &day in days {
+            if !is_unlocked(year, day, now) {
+                results.push((day, Ok(Prefetched::Locked)));
+            } else if cache.is_cached(day) {
+                results.push((day, Ok(Prefetched::Cached)));
+            } else {
+                missing.push(day);
+            }
+        }
+
+        let queue = Mutex::new(missing.into_iter());
+        let next_slot = Mutex::new(Instant::now());
+        let fetched = Mutex::new(vec![]);
+        thread::scope(|scope| {
+            for _ in 0..self.workers {
+                scope.spawn(|| loop {
+                    let Some(day) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let result = self.fetch(cache, day, &next_slot);
+                    fetched.lock().unwrap().push((day, result));
+                });
+            }
+        });
+        results.extend(fetched.into_inner().unwrap());
+        results.sort_by_key(|(day, _)| *day);
+        results
+    }
+
+    fn fetch<S: InputSource>(
+        &self,
+        cache: &InputCache<S>,
+        day: usize,
+        next_slot: &Mutex<Instant>,
+    ) -> Result<Prefetched> {
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        loop {
+            self.wait_turn(next_slot);
+            match cache.get(day) {
+                Ok(_) => return Ok(Prefetched::Fetched),
+                Err(e) if attempt < self.retries && is_transient(&e) => {
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Block until this worker may start its next request.
+    fn wait_turn(&self, next_slot: &Mutex<Instant>) {
+        let start = {
+            let mut next = next_slot.lock().unwrap();
+            let start = (*next).max(Instant::now());
+            *next = start + self.interval;
+            start
+        };
+        thread::sleep(start.saturating_duration_since(Instant::now()));
+    }
+}
+
+/// Failures that may go away if the request is simply made again.
+fn is_transient(e: &Error) -> bool {
+    match e {
+        Error::Network(_) => true,
+        Error::Http(status) => status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS,
+        _ => false,
+    }
+}
+
+// TESTS
+
+/// Fails with a 503 the first `failures` times each day is asked for.
+#[cfg(test)]
+struct FlakySource {
+    failures: usize,
+    calls: Mutex<Vec<usize>>,
+}
+
+#[cfg(test)]
+impl InputSource for FlakySource {
+    fn fetch(&self, day: usize) -> Result<Vec<u8>> {
+        let mut calls = self.calls.lock().unwrap();
+        calls.push(day);
+        if day == 4 {
+            return Err(Error::Http(StatusCode::NOT_FOUND));
+        }
+        if calls.iter().filter(|&&d| d == day).count() <= self.failures {
+            return Err(Error::Http(StatusCode::SERVICE_UNAVAILABLE));
+        }
+        Ok(format!("input for day {day}\n").into_bytes())
+    }
+}
+
+#[test]
+fn test_prefetch() {
+    let dir = crate::testing::temp_dir("prefetch");
+    let source = FlakySource {
+        failures: 2,
+        calls: Mutex::new(vec![]),
+    };
+    let cache = InputCache::new(&dir, source);
+    std::fs::write(cache.path(1), "1000\n").unwrap();
+    let now = crate::schedule::unlock_time(2022, 6);
+
+    let start = Instant::now();
+    let results = Prefetch::new()
+        .with_rate(50.0)
+        .with_retries(2, Duration::from_millis(1))
+        .run(&cache, 2022, &[1, 2, 3, 4, 10], now);
+    // Three tries each for days 2 and 3 and one for day 4, 20ms apart.
+    assert!(start.elapsed() >= Duration::from_millis(120));
+
+    let days: Vec<usize> = results.iter().map(|(day, _)| *day).collect();
+    assert_eq!(days, vec![1, 2, 3, 4, 10]);
+    assert!(matches!(results[0].1, Ok(Prefetched::Cached)));
+    assert!(matches!(results[1].1, Ok(Prefetched::Fetched)));
+    assert!(matches!(results[2].1, Ok(Prefetched::Fetched)));
+    assert!(matches!(
+        results[3].1,
+        Err(Error::Http(StatusCode::NOT_FOUND))
+    ));
+    assert!(matches!(results[4].1, Ok(Prefetched::Locked)));
+    assert_eq!(
+        std::fs::read_to_string(cache.path(3)).unwrap(),
+        "input for day 3\n"
+    );
+}
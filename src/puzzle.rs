@@ -0,0 +1,223 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::{Captures, Regex};
+
+use crate::{
+    client::AocClient,
+    error::{Error, Result},
+};
+
+/// A day's puzzle description, and the examples quoted in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub day: usize,
+    pub markdown: String,
+    pub examples: Vec<String>,
+}
+
+impl Puzzle {
+    /// Pick the description out of a puzzle page. Only the part(s) visible
+    /// to whoever fetched the page are there.
+    pub fn from_html(day: usize, html: &str) -> Puzzle {
+        let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+        let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+        let mut markdown = vec![];
+        let mut examples = vec![];
+        for a in article.captures_iter(html) {
+            markdown.push(to_markdown(&a[1]));
+            for p in pre.captures_iter(&a[1]) {
+                examples.push(strip_tags(&p[1]));
+            }
+        }
+        Puzzle {
+            day,
+            markdown: markdown.join("\n\n") + "\n",
+            examples,
+        }
+    }
+
+    /// Write the description as `NN.md` and each example as `NN-K.txt`
+    /// under `dir`, returning the files written.
+    pub fn save<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<PathBuf>> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(|e| Error::CacheDir(dir.to_path_buf(), e))?;
+        let mut written = vec![dir.join(format!("{:0>2}.md", self.day))];
+        fs::write(&written[0], &self.markdown)?;
+        for (i, example) in self.examples.iter().enumerate() {
+            let path = dir.join(format!("{:0>2}-{}.txt", self.day, i + 1));
+            fs::write(&path, example)?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+/// Example `n` (counting from 1) of `day`, as `Puzzle::save` left it under
+/// `dir`.
+pub fn read_example<P: AsRef<Path>>(dir: P, day: usize, n: usize) -> Result<String> {
+    let path = dir.as_ref().join(format!("{day:0>2}-{n}.txt"));
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::MissingExample(day),
+        _ => e.into(),
+    })
+}
+
+pub fn fetch_puzzle(client: &AocClient, day: usize) -> Result<Puzzle> {
+    let html = client
+        .get(&format!("/{}/day/{day}", client.year()))?
+        .text()?;
+    let puzzle = Puzzle::from_html(day, &html);
+    if puzzle.markdown.trim().is_empty() {
+        return Err(Error::Parse("the page has no puzzle description"));
+    }
+    Ok(puzzle)
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+    let tag = Regex::new(r"<[^>]+>").unwrap();
+    unescape(&tag.replace_all(html, ""))
+}
+
+fn to_markdown(html: &str) -> String {
+    // Code blocks are set aside first so nothing below rewrites their contents.
+    let pre = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let mut blocks = vec![];
+    let html = pre.replace_all(html, |c: &Captures| {
+        blocks.push(strip_tags(&c[1]));
+        format!("\n\u{0}{}\u{0}\n", blocks.len() - 1)
+    });
+
+    let rules = [
+        (r"(?s)<h2[^>]*>(.*?)</h2>", "\n## $1\n"),
+        (r"(?s)<p>(.*?)</p>", "\n$1\n"),
+        (r"(?s)<li>(.*?)</li>", "- $1\n"),
+        (r"</?ul>", "\n"),
+        (r"(?s)<code><em>(.*?)</em></code>", "**`$1`**"),
+        (r"(?s)<code>(.*?)</code>", "`$1`"),
+        (r"(?s)<em[^>]*>(.*?)</em>", "*$1*"),
+        (r#"(?s)<a href="([^"]*)"[^>]*>(.*?)</a>"#, "[$2]($1)"),
+        (r"<[^>]+>", ""),
+    ];
+    let mut md = html.into_owned();
+    for (pattern, replacement) in rules {
+        md = Regex::new(pattern)
+            .unwrap()
+            .replace_all(&md, replacement)
+            .into_owned();
+    }
+    md = unescape(&md);
+
+    let placeholder = Regex::new(r"\x00(\d+)\x00").unwrap();
+    md = placeholder
+        .replace_all(&md, |c: &Captures| {
+            let block = &blocks[c[1].parse::<usize>().unwrap()];
+            format!("```\n{}\n```", block.trim_end_matches('\n'))
+        })
+        .into_owned();
+    let blank_lines = Regex::new(r"\n{3,}").unwrap();
+    blank_lines.replace_all(md.trim(), "\n\n").into_owned()
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const DAY01_PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <em>Calories</em> contained by the various meals.</p>
+<p>For example, suppose the Elves finish writing their items' Calories and end up with the following list:</p>
+<pre><code>1000
+2000
+
+3000
+</code></pre>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> and <code>2000</code> Calories, a total of <code><em>3000</em></code> Calories.</li>
+</ul>
+<p>Find the Elf carrying the most Calories. <a href="/2022/day/1/input" target="_blank">Get your puzzle input</a> &amp; go.</p>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em> Elves &lt;carrying&gt; the most.</p>
+<pre><code>a &lt; b
+<em>c</em>
+</code></pre>
+</article>
+</main>
+</body>
+</html>"#;
+
+#[test]
+fn test_puzzle_from_html() {
+    let puzzle = Puzzle::from_html(1, DAY01_PAGE);
+    assert_eq!(puzzle.examples, vec!["1000\n2000\n\n3000\n", "a < b\nc\n"]);
+    assert_eq!(
+        puzzle.markdown,
+        r#"## --- Day 1: Calorie Counting ---
+
+The Elves take turns writing down the number of *Calories* contained by the various meals.
+
+For example, suppose the Elves finish writing their items' Calories and end up with the following list:
+
+```
+1000
+2000
+
+3000
+```
+
+- The first Elf is carrying food with `1000` and `2000` Calories, a total of **`3000`** Calories.
+
+Find the Elf carrying the most Calories. [Get your puzzle input](/2022/day/1/input) & go.
+
+## --- Part Two ---
+
+Find the top *three* Elves <carrying> the most.
+
+```
+a < b
+c
+```
+"#
+    );
+}
+
+#[test]
+fn test_fetch_and_save_puzzle() {
+    let server = crate::testing::Server::start(vec![(200, DAY01_PAGE)]);
+    let client = AocClient::new(Some("abc123".to_string())).with_base_url(&server.url);
+    let puzzle = fetch_puzzle(&client, 1).unwrap();
+    assert!(server.requests()[0].starts_with("GET /2022/day/1 "));
+
+    let dir = crate::testing::temp_dir("puzzle_save");
+    let written = puzzle.save(&dir).unwrap();
+    assert_eq!(
+        written,
+        vec![
+            dir.join("01.md"),
+            dir.join("01-1.txt"),
+            dir.join("01-2.txt")
+        ]
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("01-1.txt")).unwrap(),
+        "1000\n2000\n\n3000\n"
+    );
+    assert_eq!(read_example(&dir, 1, 1).unwrap(), "1000\n2000\n\n3000\n");
+    assert!(matches!(
+        read_example(&dir, 1, 3),
+        Err(Error::MissingExample(1))
+    ));
+}
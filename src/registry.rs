@@ -0,0 +1,134 @@
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use crate::{
+    bench::{Bench, DayBench},
+    report::DayReport,
+    solution::{Answer, Solution},
+};
+
+/// Object-safe view of a `Solution`, so days with different input types can
+/// sit side by side in a `Registry`.
+pub trait Runner: Send + Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> usize;
+    fn heavy(&self) -> bool;
+    fn run(&self, input: &str) -> (Answer, Answer);
+    /// Like `run`, but timing each part.
+    fn solve(&self, input: &str) -> DayReport;
+    fn bench(&self, input: &str, bench: &Bench) -> DayBench;
+}
+
+struct Entry<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Runner for Entry<S> {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn heavy(&self) -> bool {
+        S::HEAVY
+    }
+
+    fn run(&self, input: &str) -> (Answer, Answer) {
+        let data = S::parse(input);
+        (S::part1(&data), S::part2(&data))
+    }
+
+    fn solve(&self, input: &str) -> DayReport {
+        let data = S::parse(input);
+        let mut report = DayReport::new(S::YEAR, S::DAY);
+        report
+            .time(1, || S::part1(&data))
+            .time(2, || S::part2(&data));
+        report
+    }
+
+    fn bench(&self, input: &str, bench: &Bench) -> DayBench {
+        bench.run::<S>(input)
+    }
+}
+
+/// Maps years, then day numbers, to the solutions registered for them.
+#[derive(Default)]
+pub struct Registry {
+    years: BTreeMap<u16, BTreeMap<usize, Box<dyn Runner>>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution + 'static>(&mut self) -> &mut Self {
+        self.years
+            .entry(S::YEAR)
+            .or_default()
+            .insert(S::DAY, Box::new(Entry::<S>(PhantomData)));
+        self
+    }
+
+    pub fn get(&self, year: u16, day: usize) -> Option<&dyn Runner> {
+        self.years.get(&year)?.get(&day).map(|r| r.as_ref())
+    }
+
+    /// Years with at least one registered day, in ascending order.
+    pub fn years(&self) -> impl Iterator<Item = u16> + '_ {
+        self.years.keys().copied()
+    }
+
+    /// Registered days of `year`, in ascending order.
+    pub fn days(&self, year: u16) -> impl Iterator<Item = usize> + '_ {
+        self.years
+            .get(&year)
+            .into_iter()
+            .flat_map(|days| days.keys().copied())
+    }
+}
+
+// TESTS
+
+#[cfg(test)]
+struct Doubler;
+
+#[cfg(test)]
+impl Solution for Doubler {
+    const YEAR: u16 = 2021;
+    const DAY: usize = 3;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(|l| l.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().sum::<i32>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        (2 * input.iter().sum::<i32>()).into()
+    }
+}
+
+#[test]
+fn test_registry_run() {
+    let mut registry = Registry::new();
+    registry.register::<Doubler>();
+    assert_eq!(registry.years().collect::<Vec<_>>(), vec![2021]);
+    assert_eq!(registry.days(2021).collect::<Vec<_>>(), vec![3]);
+    assert_eq!(registry.days(2022).count(), 0);
+    assert!(registry.get(2021, 1).is_none());
+    assert!(registry.get(2022, 3).is_none());
+    let runner = registry.get(2021, 3).unwrap();
+    assert_eq!((runner.year(), runner.day()), (2021, 3));
+    assert_eq!(
+        runner.run("1\n2\n3"),
+        (Answer::Number(6), Answer::Number(12))
+    );
+    let report = runner.solve("1\n2\n3");
+    assert_eq!((report.year, report.day), (2021, 3));
+    assert_eq!(report.answer(2), Some(&Answer::Number(12)));
+}
@@ -0,0 +1,281 @@
+use std::{
+    io::Write,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    solution::Answer,
+};
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// One line per part, for people.
+    #[default]
+    Text,
+    /// A single JSON array with one object per day, once all are done.
+    Json,
+    /// A markdown table with one row per part.
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Format> {
+        match s.trim() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(Error::Args(format!(
+                "unknown format {s:?}; use text, json or markdown"
+            ))),
+        }
+    }
+}
+
+/// One part's answer and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    #[serde(rename = "micros", serialize_with = "micros")]
+    pub elapsed: Duration,
+}
+
+/// Everything solved for one day, for one account if it is not the default
+/// session's.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+fn micros<S: serde::Serializer>(elapsed: &Duration, s: S) -> std::result::Result<S::Ok, S::Error> {
+    s.serialize_u128(elapsed.as_micros())
+}
+
+impl DayReport {
+    pub fn new(year: u16, day: usize) -> Self {
+        DayReport {
+            year,
+            day,
+            account: None,
+            parts: vec![],
+        }
+    }
+
+    /// Solve `part` with `solve`, timing it.
+    pub fn time<A: Into<Answer>>(&mut self, part: u8, solve: impl FnOnce() -> A) -> &mut Self {
+        let start = Instant::now();
+        let answer = solve().into();
+        self.parts.push(PartReport {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        });
+        self
+    }
+
+    /// The answer to `part`, if it was solved.
+    pub fn answer(&self, part: u8) -> Option<&Answer> {
+        self.parts
+            .iter()
+            .find(|p| p.part == part)
+            .map(|p| &p.answer)
+    }
+
+    /// `Day 08`, or `Day 08 [alice]` for a named account.
+    pub fn label(&self) -> String {
+        match &self.account {
+            Some(account) => format!("Day {:0>2} [{account}]", self.day),
+            None => format!("Day {:0>2}", self.day),
+        }
+    }
+}
+
+/// `850 us` under 10 ms, `42 ms` from there on.
+pub fn format_elapsed(elapsed: Duration) -> String {
+    match elapsed.as_micros() {
+        us if us < 10_000 => format!("{us} us"),
+        _ => format!("{} ms", elapsed.as_millis()),
+    }
+}
+
+/// Prints day reports in one `Format`. Text and markdown are written as the
+/// days come in, JSON once `finish` is called.
+pub struct Reporter<W: Write> {
+    out: W,
+    format: Format,
+    timings: bool,
+    rows: usize,
+    days: Vec<DayReport>,
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        Reporter {
+            out,
+            format,
+            timings: false,
+            rows: 0,
+            days: vec![],
+        }
+    }
+
+    /// Show timings in the text format too. JSON and markdown always have
+    /// them.
+    pub fn with_timings(mut self, timings: bool) -> Self {
+        self.timings = timings;
+        self
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn add(&mut self, report: &DayReport) -> Result<()> {
+        match self.format {
+            Format::Text => {
+                for part in report.parts.iter() {
+                    let mut line = format!("{}, part {}:", report.label(), part.part);
+                    // Answers drawn on several lines, like day 10's screen,
+                    // start on a line of their own.
+                    let answer = part.answer.to_string();
+                    let answer = answer.trim_matches('\n');
+                    line.push(if answer.contains('\n') { '\n' } else { ' ' });
+                    line.push_str(answer);
+                    if self.timings {
+                        line.push_str(&format!(" ({})", format_elapsed(part.elapsed)));
+                    }
+                    writeln!(self.out, "{line}")?;
+                }
+            }
+            Format::Json => self.days.push(report.clone()),
+            Format::Markdown => {
+                if self.rows == 0 {
+                    writeln!(self.out, "| Day | Part | Answer | Time |")?;
+                    writeln!(self.out, "|:----|-----:|:-------|-----:|")?;
+                }
+                for part in report.parts.iter() {
+                    let answer = part.answer.to_string();
+                    let answer = answer
+                        .trim_matches('\n')
+                        .replace('|', "\\|")
+                        .replace('\n', "<br>");
+                    writeln!(
+                        self.out,
+                        "| {} | {} | {answer} | {} |",
+                        report.label().trim_start_matches("Day "),
+                        part.part,
+                        format_elapsed(part.elapsed)
+                    )?;
+                    self.rows += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write out whatever is still held back, and hand back the writer.
+    pub fn finish(mut self) -> Result<W> {
+        if self.format == Format::Json {
+            let json =
+                serde_json::to_string_pretty(&self.days).expect("day reports always serialise");
+            writeln!(self.out, "{json}")?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+// TESTS
+
+#[cfg(test)]
+fn sample() -> Vec<DayReport> {
+    let part = |part, answer: Answer, micros| PartReport {
+        part,
+        answer,
+        elapsed: Duration::from_micros(micros),
+    };
+    vec![
+        DayReport {
+            year: 2022,
+            day: 7,
+            account: None,
+            parts: vec![
+                part(1, Answer::Number(95_437), 850),
+                part(2, Answer::Unsolved, 42_000),
+            ],
+        },
+        DayReport {
+            year: 2022,
+            day: 10,
+            account: Some("alice".to_string()),
+            parts: vec![part(2, Answer::Text("\n#.\n.#\n".to_string()), 12)],
+        },
+    ]
+}
+
+#[cfg(test)]
+fn render(format: Format, timings: bool) -> String {
+    let mut reporter = Reporter::new(vec![], format).with_timings(timings);
+    for day in sample() {
+        reporter.add(&day).unwrap();
+    }
+    String::from_utf8(reporter.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn test_report_text() {
+    assert_eq!(
+        render(Format::Text, false),
+        "Day 07, part 1: 95437
+Day 07, part 2: no solution found
+Day 10 [alice], part 2:
+#.
+.#
+"
+    );
+    assert!(render(Format::Text, true).starts_with("Day 07, part 1: 95437 (850 us)\n"));
+}
+
+#[test]
+fn test_report_markdown() {
+    assert_eq!(
+        render(Format::Markdown, false),
+        "| Day | Part | Answer | Time |
+|:----|-----:|:-------|-----:|
+| 07 | 1 | 95437 | 850 us |
+| 07 | 2 | no solution found | 42 ms |
+| 10 [alice] | 2 | #.<br>.# | 12 us |
+"
+    );
+}
+
+#[test]
+fn test_report_json() {
+    let json: serde_json::Value = serde_json::from_str(&render(Format::Json, false)).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            {"year": 2022, "day": 7, "parts": [
+                {"part": 1, "answer": 95437, "micros": 850},
+                {"part": 2, "answer": null, "micros": 42000},
+            ]},
+            {"year": 2022, "day": 10, "account": "alice", "parts": [
+                {"part": 2, "answer": "\n#.\n.#\n", "micros": 12},
+            ]},
+        ])
+    );
+    assert_eq!("md".parse::<Format>().unwrap(), Format::Markdown);
+    assert!("yaml".parse::<Format>().is_err());
+}
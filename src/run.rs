@@ -0,0 +1,247 @@
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Result,
+    registry::{Registry, Runner},
+    report::DayReport,
+};
+
+/// How one day of a run ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(DayReport),
+    /// It never ran, because there is no solution or no input for it.
+    Failed(String),
+    /// It panicked, with this message.
+    Panicked(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayRun {
+    pub day: usize,
+    pub outcome: Outcome,
+    /// How long solving took, parsing included.
+    pub elapsed: Duration,
+}
+
+/// Every day of a run, in day order, and how long the whole run took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub runs: Vec<DayRun>,
+    pub elapsed: Duration,
+}
+
+impl Summary {
+    pub fn reports(&self) -> impl Iterator<Item = &DayReport> {
+        self.runs.iter().filter_map(|run| match &run.outcome {
+            Outcome::Solved(report) => Some(report),
+            _ => None,
+        })
+    }
+
+    pub fn failed(&self) -> usize {
+        self.runs.len() - self.reports().count()
+    }
+
+    /// A line for each day that did not get solved, then the totals.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for run in self.runs.iter() {
+            match &run.outcome {
+                Outcome::Solved(_) => (),
+                Outcome::Failed(e) => writeln!(out, "Day {:0>2}: {e}", run.day).unwrap(),
+                Outcome::Panicked(message) => {
+                    writeln!(out, "Day {:0>2}: panicked: {message}", run.day).unwrap()
+                }
+            }
+        }
+        let work: Duration = self.runs.iter().map(|run| run.elapsed).sum();
+        writeln!(
+            out,
+            "{} day(s) solved, {} failed, in {} ms ({} ms of solving)",
+            self.runs.len() - self.failed(),
+            self.failed(),
+            self.elapsed.as_millis(),
+            work.as_millis()
+        )
+        .unwrap();
+        out
+    }
+}
+
+/// Solves many days at once on the rayon pool, or one after another, and
+/// keeps going when one of them panics.
+pub struct RunAll {
+    sequential: bool,
+    heavy_first: bool,
+}
+
+impl Default for RunAll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunAll {
+    /// In parallel, starting with the heavy days.
+    pub fn new() -> Self {
+        RunAll {
+            sequential: false,
+            heavy_first: true,
+        }
+    }
+
+    pub fn with_sequential(mut self, sequential: bool) -> Self {
+        self.sequential = sequential;
+        self
+    }
+
+    /// Start the days marked `Solution::HEAVY` before the rest, so the run
+    /// is not left waiting on one of them at the end.
+    pub fn with_heavy_first(mut self, heavy_first: bool) -> Self {
+        self.heavy_first = heavy_first;
+        self
+    }
+
+    /// Solve `days` of `year`. Inputs are read with `input` one at a time
+    /// before any solving starts, so that fetching them stays polite.
+    pub fn run(
+        &self,
+        registry: &Registry,
+        year: u16,
+        days: &[usize],
+        input: impl Fn(usize) -> Result<String>,
+    ) -> Summary {
+        let start = Instant::now();
+        let mut runs = vec![];
+        let mut queue = vec![];
+        for &day in days {
+            let failed = |e: String| DayRun {
+                day,
+                outcome: Outcome::Failed(e),
+                elapsed: Duration::ZERO,
+            };
+            let Some(runner) = registry.get(year, day) else {
+                runs.push(failed("no solution registered".to_string()));
+                continue;
+            };
+            match input(day) {
+                Ok(input) => queue.push((runner, input)),
+                Err(e) => runs.push(failed(e.to_string())),
+            }
+        }
+        if self.heavy_first {
+            // Stable, so the rest keep their order.
+            queue.sort_by_key(|(runner, _)| !runner.heavy());
+        }
+
+        let solve = |(runner, input): (&dyn Runner, String)| {
+            let start = Instant::now();
+            let outcome = match panic::catch_unwind(AssertUnwindSafe(|| runner.solve(&input))) {
+                Ok(report) => Outcome::Solved(report),
+                Err(payload) => Outcome::Panicked(panic_message(payload)),
+            };
+            DayRun {
+                day: runner.day(),
+                outcome,
+                elapsed: start.elapsed(),
+            }
+        };
+        if self.sequential {
+            runs.extend(queue.into_iter().map(solve));
+        } else {
+            let queue = Mutex::new(queue.into_iter());
+            let solved = Mutex::new(vec![]);
+            rayon::scope(|scope| {
+                for _ in 0..rayon::current_num_threads() {
+                    scope.spawn(|_| loop {
+                        let Some(next) = queue.lock().unwrap().next() else {
+                            break;
+                        };
+                        let run = solve(next);
+                        solved.lock().unwrap().push(run);
+                    });
+                }
+            });
+            runs.extend(solved.into_inner().unwrap());
+        }
+        runs.sort_by_key(|run| run.day);
+        Summary {
+            runs,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "(no message)".to_string(),
+        },
+    }
+}
+
+// TESTS
+
+#[cfg(test)]
+struct Fragile;
+
+#[cfg(test)]
+impl crate::Solution for Fragile {
+    const YEAR: u16 = 2021;
+    const DAY: usize = 2;
+    const HEAVY: bool = true;
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> crate::Answer {
+        input.len().into()
+    }
+
+    fn part2(input: &Self::Input) -> crate::Answer {
+        match input.as_str() {
+            "boom" => panic!("no part 2 for {input}"),
+            _ => input.to_uppercase().into(),
+        }
+    }
+}
+
+#[test]
+fn test_run_all() {
+    let mut registry = Registry::new();
+    registry.register::<Fragile>();
+    for sequential in [false, true] {
+        let run = RunAll::new().with_sequential(sequential);
+        let summary = run.run(&registry, 2021, &[3, 2], |_| Ok("abc".to_string()));
+        let days: Vec<usize> = summary.runs.iter().map(|run| run.day).collect();
+        assert_eq!(days, vec![2, 3]);
+        let report = summary.reports().next().unwrap();
+        assert_eq!(report.answer(2), Some(&crate::Answer::from("ABC")));
+        assert_eq!(summary.failed(), 1);
+
+        let summary = run.run(&registry, 2021, &[1, 2], |day| match day {
+            2 => Ok("boom".to_string()),
+            _ => Err(crate::Error::MissingExample(day)),
+        });
+        assert_eq!(
+            summary.runs[1].outcome,
+            Outcome::Panicked("no part 2 for boom".to_string())
+        );
+        assert!(summary.render().starts_with(
+            "Day 01: no solution registered
+Day 02: panicked: no part 2 for boom
+0 day(s) solved, 2 failed, in "
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+use std::{
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::StatusCode;
+
+use crate::{
+    error::{Error, Result},
+    input::{InputCache, InputSource},
+};
+
+/// Puzzles come out at midnight US Eastern, which is UTC-5 all through
+/// December.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// How often to try again if the input is not there right at unlock time.
+const UNLOCK_RETRIES: u32 = 10;
+
+/// Where the time comes from, so waiting can be tested without waiting.
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration)
+    }
+}
+
+/// Days from 1970-01-01 to December 1st of `year`.
+fn days_to_december(year: u16) -> u64 {
+    let y = year as u64 - 1;
+    let leap_days = |y: u64| y / 4 - y / 100 + y / 400;
+    // January to November of `year` itself are 334 days, plus a leap day.
+    let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+    (y - 1969) * 365 + leap_days(y) - leap_days(1969) + 334 + leap as u64
+}
+
+/// When `day`'s puzzle of `year` came out.
+pub fn unlock_time(year: u16, day: usize) -> SystemTime {
+    let days = days_to_december(year) + day.max(1) as u64 - 1;
+    UNIX_EPOCH + Duration::from_secs(days * 24 * 60 * 60 + UNLOCK_HOUR_UTC * 60 * 60)
+}
+
+/// Whether `day`'s puzzle of `year` is out at `now`.
+pub fn is_unlocked(year: u16, day: usize, now: SystemTime) -> bool {
+    now >= unlock_time(year, day)
+}
+
+/// The first day of `year` still locked at `now`, if there is one.
+pub fn next_locked_day(year: u16, now: SystemTime) -> Option<usize> {
+    (1..=25).find(|&day| !is_unlocked(year, day, now))
+}
+
+/// `1d 02:03:04`, or `02:03:04` when less than a day is left.
+pub fn format_countdown(left: Duration) -> String {
+    let s = left.as_secs();
+    let hms = format!("{:0>2}:{:0>2}:{:0>2}", s / 3600 % 24, s / 60 % 60, s % 60);
+    match s / (24 * 3600) {
+        0 => hms,
+        days => format!("{days}d {hms}"),
+    }
+}
+
+/// Block until `day` of `year` unlocks, calling `tick` with the time left
+/// about once a second.
+pub fn wait_for_unlock<C: Clock>(clock: &C, year: u16, day: usize, mut tick: impl FnMut(Duration)) {
+    while let Ok(left) = unlock_time(year, day).duration_since(clock.now()) {
+        if left.is_zero() {
+            break;
+        }
+        tick(left);
+        clock.sleep(left.min(Duration::from_secs(1)));
+    }
+}
+
+/// Wait for `day` of `year` to unlock, then fetch its input into `cache`
+/// straight away. The site can take a moment to catch up, so a "not found" right at
+/// unlock time is tried again a few times.
+pub fn fetch_at_unlock<S: InputSource, C: Clock>(
+    cache: &InputCache<S>,
+    clock: &C,
+    year: u16,
+    day: usize,
+    tick: impl FnMut(Duration),
+) -> Result<PathBuf> {
+    wait_for_unlock(clock, year, day, tick);
+    let mut attempt = 0;
+    loop {
+        match cache.get(day) {
+            Err(Error::Http(StatusCode::NOT_FOUND)) if attempt < UNLOCK_RETRIES => {
+                clock.sleep(Duration::from_secs(1));
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+// TESTS
+
+/// A clock that only moves when slept on.
+#[cfg(test)]
+struct FakeClock {
+    now: std::sync::Mutex<SystemTime>,
+}
+
+#[cfg(test)]
+impl Clock for FakeClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+#[test]
+fn test_unlock_time() {
+    // 2022-12-01 05:00 UTC, and the same in a leap year and in 2015.
+    let first = UNIX_EPOCH + Duration::from_secs(1669870800);
+    assert_eq!(unlock_time(2022, 1), first);
+    assert_eq!(
+        unlock_time(2020, 1),
+        UNIX_EPOCH + Duration::from_secs(1606798800)
+    );
+    assert_eq!(
+        unlock_time(2015, 25),
+        UNIX_EPOCH + Duration::from_secs(1451019600)
+    );
+    assert!(!is_unlocked(2022, 1, first - Duration::from_secs(1)));
+    assert!(is_unlocked(2022, 1, first));
+    assert!(!is_unlocked(
+        2022,
+        2,
+        first + Duration::from_secs(23 * 60 * 60)
+    ));
+    assert_eq!(
+        next_locked_day(2022, first + Duration::from_secs(60)),
+        Some(2)
+    );
+    assert_eq!(next_locked_day(2022, unlock_time(2022, 25)), None);
+    assert_eq!(next_locked_day(2023, unlock_time(2022, 25)), Some(1));
+    assert_eq!(
+        format_countdown(Duration::from_secs(3 * 3600 + 62)),
+        "03:01:02"
+    );
+    assert_eq!(
+        format_countdown(Duration::from_secs(86400 + 59)),
+        "1d 00:00:59"
+    );
+}
+
+#[test]
+fn test_fetch_at_unlock() {
+    let dir = crate::testing::temp_dir("schedule");
+    let cache = InputCache::new(&dir, crate::input::MemorySource::new().with(5, "    [D]\n"));
+    let clock = FakeClock {
+        now: std::sync::Mutex::new(unlock_time(2022, 5) - Duration::from_millis(2500)),
+    };
+    let mut ticks = vec![];
+    let path = fetch_at_unlock(&cache, &clock, 2022, 5, |left| ticks.push(left)).unwrap();
+    assert_eq!(
+        ticks,
+        vec![
+            Duration::from_millis(2500),
+            Duration::from_millis(1500),
+            Duration::from_millis(500),
+        ]
+    );
+    assert_eq!(clock.now(), unlock_time(2022, 5));
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "    [D]\n");
+
+    // A day that never shows up gives up after the retries.
+    assert!(matches!(
+        fetch_at_unlock(&cache, &clock, 2022, 4, |_| ()),
+        Err(Error::Http(StatusCode::NOT_FOUND))
+    ));
+    assert_eq!(
+        clock.now(),
+        unlock_time(2022, 5) + Duration::from_secs(UNLOCK_RETRIES as u64)
+    );
+}
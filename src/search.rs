@@ -0,0 +1,252 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+/// Something to search: nodes, and the steps between them.
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+
+    /// The nodes one step away from `node`, with what each step costs.
+    fn successors(&self, node: Self::Node) -> Vec<(Self::Node, u64)>;
+}
+
+/// A `Graph` whose successors come from a closure.
+pub struct FromFn<N, F>(F, PhantomData<fn() -> N>);
+
+pub fn from_fn<N, F>(successors: F) -> FromFn<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> Vec<(N, u64)>,
+{
+    FromFn(successors, PhantomData)
+}
+
+impl<N, F> Graph for FromFn<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: Fn(N) -> Vec<(N, u64)>,
+{
+    type Node = N;
+
+    fn successors(&self, node: N) -> Vec<(N, u64)> {
+        (self.0)(node)
+    }
+}
+
+/// What a search found: how far every node it settled is from the nearest
+/// start, and the goal if it reached one.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, u64>,
+    came_from: HashMap<N, N>,
+    pub goal: Option<N>,
+}
+
+impl<N: Copy + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Search {
+            distances: HashMap::new(),
+            came_from: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: N) -> Option<u64> {
+        self.distances.get(&node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, u64> {
+        &self.distances
+    }
+
+    /// How far the goal is, if it was reached.
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.goal.and_then(|goal| self.distance(goal))
+    }
+
+    /// The way from a start to `node`, start first, if `node` was reached.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        self.distances.get(&node)?;
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(&previous) = self.came_from.get(&current) {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The way from a start to the goal, if it was reached.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.goal.and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Breadth-first search from every node of `starts` at once, counting each
+/// step as 1 whatever it costs. It stops at the first node `is_goal` accepts;
+/// with `|_| false` it measures everything reachable.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(&G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let next = search.distances[&node] + 1;
+        for (successor, _) in graph.successors(node) {
+            if let Entry::Vacant(entry) = search.distances.entry(successor) {
+                entry.insert(next);
+                search.came_from.insert(successor, node);
+                queue.push_back(successor);
+            }
+        }
+    }
+    search
+}
+
+/// Cheapest-first search from every node of `starts`, stopping at the first
+/// node `is_goal` accepts.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(&G::Node) -> bool,
+) -> Search<G::Node> {
+    astar(graph, starts, is_goal, |_| 0)
+}
+
+/// A* from every node of `starts`, stopping at the first node `is_goal`
+/// accepts. `heuristic` must never overestimate the cost left to a goal, or
+/// the path found may not be the cheapest.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    // Costs found so far; a node is settled into `search.distances` when it
+    // comes off the heap.
+    let mut best: HashMap<G::Node, u64> = HashMap::new();
+    let mut open = BinaryHeap::new();
+    for start in starts {
+        best.insert(start, 0);
+        open.push(Candidate {
+            node: start,
+            cost: 0,
+            estimate: heuristic(&start),
+        });
+    }
+    while let Some(Candidate { node, cost, .. }) = open.pop() {
+        if search.distances.contains_key(&node) || best[&node] < cost {
+            continue;
+        }
+        search.distances.insert(node, cost);
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (successor, step) in graph.successors(node) {
+            let cost = cost + step;
+            if best.get(&successor).is_none_or(|&known| cost < known) {
+                best.insert(successor, cost);
+                search.came_from.insert(successor, node);
+                open.push(Candidate {
+                    node: successor,
+                    cost,
+                    estimate: cost + heuristic(&successor),
+                });
+            }
+        }
+    }
+    search
+}
+
+/// A node waiting on the heap, which pops the lowest estimate first.
+struct Candidate<N> {
+    node: N,
+    cost: u64,
+    estimate: u64,
+}
+
+impl<N> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N> Eq for Candidate<N> {}
+
+// TESTS
+
+/// A to D directly costs 10, through B and C it costs 3.
+#[cfg(test)]
+fn diamond(node: char) -> Vec<(char, u64)> {
+    match node {
+        'A' => vec![('B', 1), ('D', 10)],
+        'B' => vec![('C', 1)],
+        'C' => vec![('D', 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn test_bfs() {
+    let graph = from_fn(diamond);
+    let search = bfs(&graph, ['A'], |&n| n == 'D');
+    assert_eq!(search.goal_distance(), Some(1));
+    assert_eq!(search.path(), Some(vec!['A', 'D']));
+
+    let search = bfs(&graph, ['B', 'C'], |_| false);
+    assert_eq!(search.goal, None);
+    assert_eq!(search.distance('D'), Some(1));
+    assert_eq!(search.distance('A'), None);
+    assert_eq!(search.path_to('D'), Some(vec!['C', 'D']));
+}
+
+#[test]
+fn test_dijkstra_and_astar() {
+    let graph = from_fn(diamond);
+    let search = dijkstra(&graph, ['A'], |&n| n == 'D');
+    assert_eq!(search.goal_distance(), Some(3));
+    assert_eq!(search.path(), Some(vec!['A', 'B', 'C', 'D']));
+    assert_eq!(dijkstra(&graph, ['D'], |&n| n == 'A').goal, None);
+
+    // On a line, how far off the end each point is never overestimates.
+    let line = from_fn(|x: i32| vec![(x - 1, 1), (x + 1, 1)]);
+    let search = astar(
+        &line,
+        [0, 20],
+        |&x| x == 7,
+        |&x| (7 - x).unsigned_abs() as u64,
+    );
+    assert_eq!(search.goal_distance(), Some(7));
+    assert_eq!(search.path().unwrap().len(), 8);
+    // Only the way straight there was looked at.
+    assert!(search.distance(-1).is_none() && search.distance(20).is_none());
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+/// The answer to one part of a puzzle, whatever type the day computes it in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "no solution found"),
+        }
+    }
+}
+
+/// Numbers as JSON numbers, text as strings, and no solution as `null`.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => s.serialize_i64(*n),
+            Answer::Text(t) => s.serialize_str(t),
+            Answer::Unsolved => s.serialize_none(),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i64)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, u32, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        match answer {
+            Some(a) => a.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/// The shape every day shares: parse the puzzle input once, then answer both
+/// parts from the parsed form.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: usize;
+    /// Takes seconds rather than milliseconds, so runs of every day should
+    /// start it first.
+    const HEAVY: bool = false;
+    type Input;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+// TESTS
+
+#[test]
+fn test_answer_from() {
+    assert_eq!(Answer::from(42i32), Answer::Number(42));
+    assert_eq!(
+        Answer::from(2_713_310_158usize),
+        Answer::Number(2_713_310_158)
+    );
+    assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+    assert_eq!(Answer::from(None::<i32>), Answer::Unsolved);
+    assert_eq!(Answer::from(Some(24_933_642)), Answer::Number(24_933_642));
+}
@@ -0,0 +1,204 @@
+use std::{fmt, time::Duration};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::AocClient,
+    error::{Error, Result},
+    ledger::Ledger,
+    solution::Answer,
+};
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous attempt.
+    RateLimited(Duration),
+    /// The part had already been solved, so the answer was not checked.
+    AlreadySolved,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::RateLimited(wait) => {
+                write!(f, "rate limited, try again in {}s", wait.as_secs())
+            }
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// The form value to send for `answer`, if it can be sent at all.
+fn answer_value(answer: &Answer) -> Result<String> {
+    match answer {
+        Answer::Number(n) => Ok(n.to_string()),
+        Answer::Text(s) if !s.trim().is_empty() && !s.trim().contains('\n') => {
+            Ok(s.trim().to_string())
+        }
+        Answer::Text(_) => Err(Error::Refused(
+            "multi-line answers have to be read and typed in by hand".to_string(),
+        )),
+        Answer::Unsolved => Err(Error::Refused("there is no answer to submit".to_string())),
+    }
+}
+
+/// Make sense of the page the site sends back after a submission.
+pub fn parse_outcome(page: &str) -> Result<Outcome> {
+    if page.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if page.contains("Did you already complete it?") {
+        Ok(Outcome::AlreadySolved)
+    } else if page.contains("You gave an answer too recently") {
+        let re = Regex::new(r"You have (?:(\d+)m ?)?(?:(\d+)s )?left to wait").unwrap();
+        let wait = match re.captures(page) {
+            Some(c) => {
+                let field = |i| c.get(i).map_or(0, |m| m.as_str().parse().unwrap());
+                field(1) * 60 + field(2)
+            }
+            None => 60,
+        };
+        Ok(Outcome::RateLimited(Duration::from_secs(wait)))
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Wrong)
+        }
+    } else {
+        Err(Error::Parse("could not make sense of the reply"))
+    }
+}
+
+fn post(client: &AocClient, day: usize, part: u8, value: &str) -> Result<Outcome> {
+    let level = part.to_string();
+    let page = client
+        .post_form(
+            &format!("/{}/day/{day}/answer", client.year()),
+            &[("level", &level), ("answer", value)],
+        )?
+        .text()?;
+    parse_outcome(&page)
+}
+
+/// Submit `answer` for `part` (1 or 2) of `day`.
+pub fn submit(client: &AocClient, day: usize, part: u8, answer: &Answer) -> Result<Outcome> {
+    post(client, day, part, &answer_value(answer)?)
+}
+
+/// Like `submit`, but consult `ledger` first and record the verdict in it.
+pub fn submit_recorded(
+    client: &AocClient,
+    ledger: &mut Ledger,
+    day: usize,
+    part: u8,
+    answer: &Answer,
+) -> Result<Outcome> {
+    let value = answer_value(answer)?;
+    ledger.check(part, &value)?;
+    let outcome = post(client, day, part, &value)?;
+    ledger.record(part, &value, &outcome)?;
+    Ok(outcome)
+}
+
+// TESTS
+
+#[test]
+fn test_parse_outcome() {
+    assert_eq!(
+        parse_outcome("<article><p>That's the right answer!  You are <em>one gold star</em> closer to collecting enough star fruit.</p></article>").unwrap(),
+        Outcome::Correct
+    );
+    assert_eq!(
+        parse_outcome("<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again.</p></article>").unwrap(),
+        Outcome::TooHigh
+    );
+    assert_eq!(
+        parse_outcome(
+            "<article><p>That's not the right answer; your answer is too low.</p></article>"
+        )
+        .unwrap(),
+        Outcome::TooLow
+    );
+    assert_eq!(
+        parse_outcome("<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>").unwrap(),
+        Outcome::Wrong
+    );
+    assert_eq!(
+        parse_outcome("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.</p></article>").unwrap(),
+        Outcome::RateLimited(Duration::from_secs(37))
+    );
+    assert_eq!(
+        parse_outcome("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.</p></article>").unwrap(),
+        Outcome::RateLimited(Duration::from_secs(242))
+    );
+    assert_eq!(
+        parse_outcome("<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>").unwrap(),
+        Outcome::AlreadySolved
+    );
+    assert!(parse_outcome("<html>Internal error</html>").is_err());
+}
+
+#[test]
+fn test_submit() {
+    let server = crate::testing::Server::start(vec![(
+        200,
+        "<article><p>That's the right answer!</p></article>",
+    )]);
+    let client = AocClient::new(Some("abc123".to_string())).with_base_url(&server.url);
+    assert_eq!(
+        submit(&client, 1, 1, &Answer::Number(24000)).unwrap(),
+        Outcome::Correct
+    );
+    let request = &server.requests()[0];
+    assert!(request.starts_with("POST /2022/day/1/answer "));
+    assert!(request.contains("cookie: session=abc123"));
+    assert!(request.ends_with("level=1&answer=24000"));
+
+    assert!(matches!(
+        submit(
+            &client,
+            10,
+            2,
+            &Answer::Text("\n##..##\n#..#..\n".to_string())
+        ),
+        Err(Error::Refused(_))
+    ));
+    assert!(matches!(
+        submit(&client, 7, 2, &Answer::Unsolved),
+        Err(Error::Refused(_))
+    ));
+}
+
+#[test]
+fn test_submit_recorded() {
+    let server = crate::testing::Server::start(vec![
+        (
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        ),
+        (200, "<article><p>That's the right answer!</p></article>"),
+    ]);
+    let client = AocClient::new(Some("abc123".to_string())).with_base_url(&server.url);
+    let mut ledger = Ledger::open(crate::testing::temp_dir("submit_recorded"), 15).unwrap();
+    let mut submit = |answer: i64| submit_recorded(&client, &mut ledger, 15, 1, &answer.into());
+
+    assert_eq!(submit(4000).unwrap(), Outcome::TooLow);
+    // Neither of these may reach the server.
+    assert!(matches!(submit(4000), Err(Error::Refused(_))));
+    assert!(matches!(submit(3999), Err(Error::Refused(_))));
+    assert_eq!(submit(5100).unwrap(), Outcome::Correct);
+    assert_eq!(server.requests().len(), 2);
+    assert_eq!(ledger.accepted(1), Some("5100"));
+}
@@ -0,0 +1,39 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day15;
+pub mod day16;
+pub mod day19;
+pub mod day20;
+
+use crate::registry::Registry;
+
+/// Add every day of 2022 to `registry`.
+pub fn register(registry: &mut Registry) {
+    registry
+        .register::<day01::Day01>()
+        .register::<day02::Day02>()
+        .register::<day03::Day03>()
+        .register::<day04::Day04>()
+        .register::<day05::Day05>()
+        .register::<day06::Day06>()
+        .register::<day07::Day07>()
+        .register::<day08::Day08>()
+        .register::<day09::Day09>()
+        .register::<day10::Day10>()
+        .register::<day11::Day11>()
+        .register::<day12::Day12>()
+        .register::<day15::Day15>()
+        .register::<day16::Day16>()
+        .register::<day19::Day19>()
+        .register::<day20::Day20>();
+}
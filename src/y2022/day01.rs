@@ -0,0 +1,83 @@
+use crate::{Answer, Solution};
+
+pub fn parse(input: &str) -> Vec<i32> {
+    let mut data: Vec<i32> = vec![];
+    let mut subtotal = 0;
+    for item in input.lines() {
+        if item.is_empty() {
+            data.push(subtotal);
+            subtotal = 0;
+        } else {
+            subtotal += item
+                .parse::<i32>()
+                .unwrap_or_else(|_| panic!("Bad input line: {}", item));
+        }
+    }
+    if subtotal > 0 {
+        data.push(subtotal)
+    }
+    data.sort_by(|a, b| b.cmp(a));
+    data
+}
+
+pub fn part1(data: &[i32]) -> i32 {
+    data[0]
+}
+
+pub fn part2(data: &[i32]) -> i32 {
+    data[0..3].iter().sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 1;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+// Tests
+
+#[allow(dead_code)]
+const DAY1_EXAMPLE: &str = r#"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000"#;
+
+#[test]
+fn test_day01_prep() {
+    assert_eq!(parse(DAY1_EXAMPLE), vec![24000, 11000, 10000, 6000, 4000]);
+}
+
+#[test]
+fn test_day01_part1() {
+    let input = DAY1_EXAMPLE;
+    assert_eq!(part1(&parse(input)), 24000);
+}
+
+#[test]
+fn test_day01_part2() {
+    assert_eq!(part2(&parse(DAY1_EXAMPLE)), 45000);
+}
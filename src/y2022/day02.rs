@@ -0,0 +1,131 @@
+use crate::{Answer, Solution};
+
+pub fn parse(input: &str) -> Vec<(String, String)> {
+    let mut data = vec![];
+    for line in input.lines() {
+        let x: Vec<&str> = line.split(' ').collect();
+        data.push((x[0].to_string(), x[1].to_string()));
+    }
+    data
+}
+
+#[derive(Clone)]
+enum Play {
+    Rock = 0,
+    Paper = 1,
+    Scissors = 2,
+}
+
+impl Play {
+    fn from_i32(i: i32) -> Self {
+        match i {
+            0 => Play::Rock,
+            1 => Play::Paper,
+            2 => Play::Scissors,
+            _ => panic!("Cannot convert {} to a play!", i),
+        }
+    }
+}
+
+fn elf_play(p1: &str) -> Play {
+    match p1 {
+        "A" => Play::Rock,
+        "B" => Play::Paper,
+        "C" => Play::Scissors,
+        _ => panic!("Bad input on first column!"),
+    }
+}
+
+fn score(p1: &Play, p2: &Play) -> i32 {
+    let elf = p1.clone() as i32;
+    let me = p2.clone() as i32;
+    match (me - elf).rem_euclid(3) {
+        0 => (me + 1) + 3, // Draw
+        1 => (me + 1) + 6, // Win
+        2 => me + 1,       // Loss
+        _ => panic!(
+            "No way for this to happen: {} {} {}",
+            me,
+            elf,
+            (me - elf) % 3
+        ),
+    }
+}
+
+fn p1_score(p1: &str, p2: &str) -> i32 {
+    let p2 = match p2 {
+        "X" => Play::Rock,
+        "Y" => Play::Paper,
+        "Z" => Play::Scissors,
+        _ => panic!("Bad input on second column!"),
+    };
+    score(&elf_play(p1), &p2)
+}
+
+pub fn part1(data: &[(String, String)]) -> i32 {
+    data.iter().map(|(p1, p2)| p1_score(p1, p2)).sum()
+}
+
+fn p2_score(p1: &str, p2: &str) -> i32 {
+    let elf = elf_play(p1);
+    let me = match p2 {
+        "X" => Play::from_i32((elf.clone() as i32 - 1).rem_euclid(3)),
+        "Y" => Play::from_i32((elf.clone() as i32).rem_euclid(3)),
+        "Z" => Play::from_i32((elf.clone() as i32 + 1).rem_euclid(3)),
+        _ => panic!("Bad input on second column!"),
+    };
+    score(&elf, &me)
+}
+
+pub fn part2(data: &[(String, String)]) -> i32 {
+    data.iter().map(|(p1, p2)| p2_score(p1, p2)).sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 2;
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const DAY2_EXAMPLE: &str = r#"A Y
+B X
+C Z"#;
+
+#[test]
+fn test_day02_prep() {
+    assert_eq!(
+        parse(DAY2_EXAMPLE),
+        vec![
+            (String::from("A"), String::from("Y")),
+            (String::from("B"), String::from("X")),
+            (String::from("C"), String::from("Z"))
+        ]
+    );
+}
+
+#[test]
+fn test_day02_part1() {
+    assert_eq!(part1(&parse(DAY2_EXAMPLE)), 15);
+}
+
+#[test]
+fn test_day02_part2() {
+    assert_eq!(part2(&parse(DAY2_EXAMPLE)), 12);
+}
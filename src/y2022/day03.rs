@@ -0,0 +1,108 @@
+use std::collections::{hash_map::RandomState, HashSet};
+
+use crate::{Answer, Solution};
+
+pub fn parse(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+fn find_common_in_compartments(s: &str) -> HashSet<char> {
+    let mid = s.len() / 2;
+    let compartment1: HashSet<char, RandomState> = HashSet::from_iter(s[0..mid].chars());
+    let compartment2: HashSet<char, RandomState> = HashSet::from_iter(s[mid..].chars());
+    compartment1
+        .intersection(&compartment2)
+        .map(|c| c.to_owned())
+        .collect()
+}
+
+fn find_common_in_groups(v: &[String]) -> HashSet<char> {
+    let elf1: HashSet<char, RandomState> = HashSet::from_iter(v[0].chars());
+    let elf2: HashSet<char, RandomState> = HashSet::from_iter(v[1].chars());
+    let elf3: HashSet<char, RandomState> = HashSet::from_iter(v[2].chars());
+    let tmp = HashSet::from_iter(elf1.intersection(&elf2).map(|c| c.to_owned()));
+    tmp.intersection(&elf3).map(|c| c.to_owned()).collect()
+}
+
+fn get_priority(c: &char) -> i32 {
+    if *c as i32 > 0x60 {
+        (*c as i32) - 0x60
+    } else {
+        (*c as i32 - 0x40) + 26
+    }
+}
+
+pub fn part1(data: &[String]) -> i32 {
+    let mut total = 0;
+    for bag in data {
+        for common in find_common_in_compartments(bag) {
+            total += get_priority(&common);
+        }
+    }
+    total
+}
+
+pub fn part2(data: &[String]) -> i32 {
+    let mut total = 0;
+    for group in data.chunks(3) {
+        for common in find_common_in_groups(group) {
+            total += get_priority(&common)
+        }
+    }
+    total
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 3;
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const DAY3_EXAMPLE: &str = r#"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw"#;
+
+#[test]
+fn test_day03_prep() {
+    assert_eq!(
+        parse(DAY3_EXAMPLE),
+        vec![
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw"
+        ]
+    )
+}
+
+#[test]
+fn test_day03_part1() {
+    assert_eq!(part1(&parse(DAY3_EXAMPLE)), 157)
+}
+
+#[test]
+fn test_day03_part2() {
+    assert_eq!(part2(&parse(DAY3_EXAMPLE)), 70)
+}
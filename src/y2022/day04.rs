@@ -0,0 +1,100 @@
+use crate::{interval::Interval, Answer, Solution};
+use sscanf::sscanf;
+
+pub fn parse(input: &str) -> Vec<(i32, i32, i32, i32)> {
+    input
+        .lines()
+        .map(|line| sscanf!(line, "{i32}-{i32},{i32}-{i32}").expect("Bad input"))
+        .collect()
+}
+
+fn sections(&(r1l, r1h, r2l, r2h): &(i32, i32, i32, i32)) -> (Interval, Interval) {
+    (
+        Interval::new(r1l as i64, r1h as i64),
+        Interval::new(r2l as i64, r2h as i64),
+    )
+}
+
+fn contains(regions: &(i32, i32, i32, i32)) -> bool {
+    let (first, second) = sections(regions);
+    first.covers(&second) || second.covers(&first)
+}
+
+pub fn part1(data: &[(i32, i32, i32, i32)]) -> i32 {
+    let mut count = 0;
+    for regions in data {
+        if contains(regions) {
+            count += 1
+        }
+    }
+    count
+}
+
+fn overlaps(regions: &(i32, i32, i32, i32)) -> bool {
+    let (first, second) = sections(regions);
+    first.overlaps(&second)
+}
+
+pub fn part2(data: &[(i32, i32, i32, i32)]) -> i32 {
+    let mut count = 0;
+    for regions in data {
+        if overlaps(regions) {
+            count += 1
+        }
+    }
+    count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 4;
+    type Input = Vec<(i32, i32, i32, i32)>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const DAY4_EXAMPLE: &str = r#"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8"#;
+
+#[test]
+fn test_day04_prep() {
+    assert_eq!(
+        parse(DAY4_EXAMPLE),
+        [
+            (2, 4, 6, 8),
+            (2, 3, 4, 5),
+            (5, 7, 7, 9),
+            (2, 8, 3, 7),
+            (6, 6, 4, 6),
+            (2, 6, 4, 8)
+        ]
+    );
+}
+#[test]
+fn test_day04_part1() {
+    assert_eq!(part1(&parse(DAY4_EXAMPLE)), 2);
+}
+
+#[test]
+fn test_day04_part2() {
+    assert_eq!(part2(&parse(DAY4_EXAMPLE)), 4);
+}
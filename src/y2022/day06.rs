@@ -0,0 +1,102 @@
+use crate::{Answer, Solution};
+
+pub fn parse(input: &str) -> String {
+    input.to_string()
+}
+
+fn has_duplicates(window: &str) -> bool {
+    let mut chars: Vec<char> = window.chars().collect();
+    chars.sort();
+    for i in 0..(chars.len() - 1) {
+        if chars[i] == chars[i + 1] {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn part1(signal: &str) -> i32 {
+    let mut i = 0;
+    while i < signal.len() - 4 {
+        let window = &signal[i..(i + 4)];
+        if has_duplicates(window) {
+            i += 1;
+        } else {
+            break;
+        };
+    }
+    (i as i32) + 4
+}
+
+pub fn part2(signal: &str) -> i32 {
+    let mut i = 0;
+    while i < signal.len() - 14 {
+        let window = &signal[i..(i + 14)];
+        if has_duplicates(window) {
+            i += 1;
+        } else {
+            break;
+        };
+    }
+    (i as i32) + 14
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 6;
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const DAY06_EXAMPLES_P1: (&str, &str, &str, &str) = (
+    "bvwbjplbgvbhsrlpgdmjqwftvncz",
+    "nppdvjthqldpwncqszvftbrmjlhg",
+    "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+    "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+);
+
+#[allow(dead_code)]
+const DAY06_EXAMPLES_P2: (&str, &str, &str, &str, &str) = (
+    "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+    "bvwbjplbgvbhsrlpgdmjqwftvncz",
+    "nppdvjthqldpwncqszvftbrmjlhg",
+    "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+    "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+);
+
+#[allow(dead_code)]
+const DAY06_EXPECTED_PART1: (i32, i32, i32, i32) = (5, 6, 10, 11);
+#[allow(dead_code)]
+const DAY06_EXPECTED_PART2: (i32, i32, i32, i32, i32) = (19, 23, 23, 29, 26);
+
+#[test]
+fn test_day06_prep() {}
+#[test]
+fn test_day06_part1() {
+    for _ in 0..4 {
+        assert_eq!(DAY06_EXPECTED_PART1.0, part1(DAY06_EXAMPLES_P1.0));
+    }
+}
+
+#[test]
+fn test_day06_part2() {
+    for _ in 0..4 {
+        assert_eq!(DAY06_EXPECTED_PART2.0, part2(DAY06_EXAMPLES_P2.0));
+    }
+}
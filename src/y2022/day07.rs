@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use sscanf::sscanf;
+use uuid::Uuid;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+struct Directory {
+    id: Uuid,
+    parent: Option<Uuid>,
+    children: HashMap<String, Uuid>,
+    file_total: i32,
+}
+
+pub struct MasterIndex {
+    directories: HashMap<Uuid, Directory>,
+}
+
+impl Directory {
+    fn new(parent: Option<Uuid>) -> Directory {
+        Directory {
+            id: Uuid::new_v4(),
+            parent,
+            children: HashMap::new(),
+            file_total: 0,
+        }
+    }
+
+    fn add_file(&mut self, size: i32) {
+        self.file_total += size;
+    }
+}
+
+/// Every directory, and the id of the root one.
+pub type Filesystem = (MasterIndex, Uuid);
+
+pub fn parse(input: &str) -> Filesystem {
+    // Assuming first line is the root directory, i.e. (cd /)
+    let listing: Vec<String> = input
+        .to_string()
+        .lines()
+        .map(|x| x.to_string())
+        .skip(1)
+        .collect();
+    let mut index = MasterIndex {
+        directories: HashMap::new(),
+    };
+    let root = Directory::new(None);
+    let root_id = root.id;
+    let mut current_id = root_id;
+    index.directories.insert(root.id, root);
+
+    for line in listing.iter() {
+        if line.starts_with('$') {
+            // This is a command, not a directory listing
+            match sscanf!(line, "$ {} {}", str, str) {
+                Ok(("cd", "..")) => {
+                    current_id = match index.directories.get(&current_id).unwrap().parent {
+                        Some(id) => id,
+                        None => panic!("Cannot go up from root directory"),
+                    };
+                }
+                Ok(("cd", dir)) => {
+                    current_id = match index
+                        .directories
+                        .get(&current_id)
+                        .unwrap()
+                        .children
+                        .get(dir)
+                    {
+                        Some(id) => *id,
+                        None => panic!("Cannot find directory {}", dir),
+                    };
+                }
+                Ok((a, b)) => {
+                    panic!("Unknown command: {} {}", a, b);
+                }
+                Err(_) => {
+                    // Must have been an "ls"
+                }
+            }
+        } else {
+            // This is a directory listing
+            let mut parts = line.split_whitespace();
+            let part1 = parts.next().unwrap().to_string();
+            let part2 = parts.next().unwrap().to_string();
+            if part1.starts_with('d') {
+                let name = part2;
+                // This is a directory
+                let dir = Directory::new(Some(current_id));
+                index
+                    .directories
+                    .get_mut(&current_id)
+                    .unwrap()
+                    .children
+                    .insert(name, dir.id);
+                index.directories.insert(dir.id, dir);
+            } else {
+                // This is a file
+                let size: i32 = part1.parse().unwrap();
+                index
+                    .directories
+                    .get_mut(&current_id)
+                    .unwrap()
+                    .add_file(size);
+            }
+        }
+    }
+    (index, root_id)
+}
+
+fn dir_size(root: Uuid, index: &MasterIndex) -> i32 {
+    let current_id = root;
+    let current_dir = index.directories.get(&current_id).unwrap();
+    let mut total = current_dir.file_total;
+    for child_id in current_dir.children.values() {
+        total += dir_size(*child_id, index);
+    }
+    total
+}
+
+pub fn part1((index, _): &Filesystem) -> i32 {
+    //not very efficient, but what the hell...
+    //TODO: maybe add memoization later
+    let mut grand_total = 0;
+    for dir in index.directories.values() {
+        let dir_total = dir_size(dir.id, index);
+        if dir_total <= 100000 {
+            grand_total += dir_total;
+        }
+    }
+    grand_total
+}
+
+pub fn part2((index, root_id): &Filesystem) -> Option<i32> {
+    let total_space = 70_000_000;
+    let required_space = 30_000_000;
+    let max_full = total_space - required_space;
+    let used = dir_size(*root_id, index);
+    let min_delete = used - max_full;
+    let mut dir_sizes = vec![];
+    for dir in index.directories.values() {
+        dir_sizes.push(dir_size(dir.id, index));
+    }
+    dir_sizes.sort();
+    dir_sizes.into_iter().find(|&ds| ds >= min_delete)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 7;
+    type Input = Filesystem;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const DAY07_EXAMPLE: &str = r#"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k"#;
+
+#[test]
+fn test_day07_prep() {
+    let (index, root_id) = parse(DAY07_EXAMPLE);
+    assert_eq!(index.directories.len(), 4);
+    assert_eq!(dir_size(root_id, &index), 48_381_165);
+}
+#[test]
+fn test_day07_part1() {
+    assert_eq!(part1(&parse(DAY07_EXAMPLE)), 95_437);
+}
+
+#[test]
+fn test_day07_part2() {
+    assert_eq!(
+        part2(&parse(DAY07_EXAMPLE)).expect("No solution found"),
+        24_933_642
+    );
+}
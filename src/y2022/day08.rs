@@ -0,0 +1,97 @@
+use crate::{
+    grid::{Grid, ORTHOGONAL},
+    Answer, Solution,
+};
+
+/// The height of every tree, 0 to 9.
+pub type Forest = Grid<u8>;
+
+pub fn parse(input: &str) -> Forest {
+    Grid::parse(input, |_, c| {
+        c.to_digit(10).expect("Invalid character") as u8
+    })
+}
+
+pub fn part1(trees: &Forest) -> usize {
+    trees
+        .iter()
+        .filter(|&(t, height)| {
+            ORTHOGONAL
+                .iter()
+                .any(|&dir| trees.ray(t, dir).all(|(_, other)| other < height))
+        })
+        .count()
+}
+
+pub fn part2(trees: &Forest) -> usize {
+    trees
+        .iter()
+        .map(|(t, height)| {
+            ORTHOGONAL
+                .iter()
+                .map(|&dir| {
+                    let mut seen = 0;
+                    for (_, other) in trees.ray(t, dir) {
+                        seen += 1;
+                        if other >= height {
+                            break;
+                        }
+                    }
+                    seen
+                })
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 8;
+    type Input = Forest;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+// TESTS
+
+#[allow(dead_code)]
+const DAY08_EXAMPLE: &str = r#"30373
+25512
+65332
+33549
+35390"#;
+
+#[test]
+fn test_day08_prep() {
+    let trees = parse(DAY08_EXAMPLE);
+    assert_eq!((trees.width(), trees.height()), (5, 5));
+    assert_eq!(trees[(0, 0)], 3);
+    assert_eq!(trees[(1, 2)], 5);
+    assert_eq!(trees[(3, 4)], 9);
+    assert_eq!(trees[(3, 3)], 4);
+    assert_eq!(trees[(0, 2)], 6);
+    assert_eq!(trees[(2, 2)], 3);
+    assert_eq!(trees[(4, 4)], 0);
+}
+#[test]
+fn test_day08_part1() {
+    assert_eq!(21, part1(&parse(DAY08_EXAMPLE)));
+}
+
+#[test]
+fn test_day08_part2() {
+    assert_eq!(8, part2(&parse(DAY08_EXAMPLE)));
+}
@@ -0,0 +1,126 @@
+use crate::{
+    grid::Grid,
+    search::{self, Graph},
+    Answer, Solution,
+};
+
+pub type Position = (i32, i32);
+
+/// The start, the end, and the elevation of every square.
+pub type Heightmap = (Position, Position, Grid<u8>);
+
+pub fn parse(input: &str) -> Heightmap {
+    let mut spos: Option<Position> = None;
+    let mut epos: Option<Position> = None;
+    let map = Grid::parse(input, |pos, ch| match ch {
+        'S' => {
+            spos = Some(pos);
+            0
+        }
+        'E' => {
+            epos = Some(pos);
+            25
+        }
+        _ => (ch as u8) - 97,
+    });
+    (spos.unwrap(), epos.unwrap(), map)
+}
+
+/// Climbing at most one step up at a time, down as far as you like.
+fn climb(map: &Grid<u8>) -> impl Graph<Node = Position> + '_ {
+    search::from_fn(move |pos: Position| {
+        map.neighbours4(pos)
+            .filter(|&n| map[n] <= map[pos] + 1)
+            .map(|n| (n, 1))
+            .collect()
+    })
+}
+
+/// The fewest steps from `start` to `end`, if it can be reached at all.
+pub fn fewest_steps(start: Position, end: Position, map: &Grid<u8>) -> Option<usize> {
+    let distance = |(x, y): &Position| (x.abs_diff(end.0) + y.abs_diff(end.1)) as u64;
+    search::astar(&climb(map), [start], |&pos| pos == end, distance)
+        .goal_distance()
+        .map(|steps| steps as usize)
+}
+
+pub fn part1((start, end, map): &Heightmap) -> usize {
+    fewest_steps(*start, *end, map).unwrap()
+}
+
+/// Climbing backwards: at most one step down at a time, up as far as you
+/// like.
+fn descend(map: &Grid<u8>) -> impl Graph<Node = Position> + '_ {
+    search::from_fn(move |pos: Position| {
+        map.neighbours4(pos)
+            .filter(|&n| map[pos] <= map[n] + 1)
+            .map(|n| (n, 1))
+            .collect()
+    })
+}
+
+/// How many steps every square is from `end`, or `None` where there is no
+/// way up. One search back down from `end` finds them all.
+pub fn steps_to_summit(end: Position, map: &Grid<u8>) -> Grid<Option<usize>> {
+    let search = search::bfs(&descend(map), [end], |_| false);
+    let mut field = Grid::new(map.width(), map.height(), None);
+    for (&pos, &steps) in search.distances() {
+        field[pos] = Some(steps as usize);
+    }
+    field
+}
+
+pub fn part2((_, end, map): &Heightmap) -> usize {
+    let field = steps_to_summit(*end, map);
+    map.iter()
+        .filter(|&(_, &elevation)| elevation == 0)
+        .filter_map(|(pos, _)| field[pos])
+        .min()
+        .unwrap()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 12;
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[test]
+fn test_day12_part1() {
+    assert_eq!(31, part1(&parse(DAY12_EXAMPLE)))
+}
+
+#[test]
+fn test_day12_part2() {
+    assert_eq!(29, part2(&parse(DAY12_EXAMPLE)))
+}
+
+#[test]
+fn test_day12_steps_to_summit() {
+    let (start, end, map) = parse(DAY12_EXAMPLE);
+    let field = steps_to_summit(end, &map);
+    assert_eq!(field[end], Some(0));
+    assert_eq!(field[start], Some(31));
+    assert_eq!(field[(0, 4)], Some(29));
+}
+
+#[allow(dead_code)]
+const DAY12_EXAMPLE: &str = r#"Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi"#;
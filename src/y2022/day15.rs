@@ -0,0 +1,351 @@
+use crate::{
+    interval::{Interval, IntervalSet},
+    Answer, Solution,
+};
+use sscanf::sscanf;
+
+/// The row part 1 asks about.
+pub const ROW: i64 = 2_000_000;
+
+/// The square part 2 searches, the same along both axes.
+pub const LIMITS: (i64, i64) = (0, 4_000_000);
+
+/// The row and the square the puzzle's example uses instead.
+pub const EXAMPLE_ROW: i64 = 10;
+pub const EXAMPLE_LIMITS: (i64, i64) = (0, 20);
+
+pub fn parse(input: &str) -> Vec<(i64, i64, i64, i64)> {
+    let mut locations: Vec<(i64, i64, i64, i64)> = vec![];
+    for line in input.lines() {
+        let location = sscanf!(
+            line,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            i64,
+            i64,
+            i64,
+            i64
+        )
+        .unwrap();
+        locations.push(location);
+    }
+    locations
+}
+
+pub fn part1(sensors: &[(i64, i64, i64, i64)]) -> i64 {
+    no_beacon_count(sensors, ROW)
+}
+
+/// How many positions in row `at_line` cannot hold a beacon.
+pub fn no_beacon_count(sensors: &[(i64, i64, i64, i64)], at_line: i64) -> i64 {
+    let mut covered = coverage(sensors, at_line);
+    for &(_, _, bx, by) in sensors.iter() {
+        if by == at_line {
+            covered.remove_point(bx);
+        }
+    }
+    covered.len()
+}
+
+/// The positions of row `at_line` that some sensor is closer to than its
+/// beacon, or as close.
+pub fn coverage(sensors: &[(i64, i64, i64, i64)], at_line: i64) -> IntervalSet {
+    sensors
+        .iter()
+        .filter_map(|(sx, sy, bx, by)| {
+            let min_radius = (sx - bx).abs() + (sy - by).abs();
+            let proj_size = min_radius - (at_line - sy).abs();
+            (proj_size >= 0).then(|| Interval::new(sx - proj_size, sx + proj_size))
+        })
+        .collect()
+}
+
+pub fn part2(sensors: &[(i64, i64, i64, i64)]) -> Option<i64> {
+    tuning_frequency(sensors, LIMITS)
+}
+
+/// The tuning frequency of the position within `limits` that no sensor
+/// covers, if there is exactly one. It stops looking as soon as it sees a
+/// second.
+pub fn tuning_frequency(sensors: &[(i64, i64, i64, i64)], limits: (i64, i64)) -> Option<i64> {
+    let mut holes = uncovered(sensors, limits);
+    let (y, gap) = holes.next()?;
+    (gap.len() == 1 && holes.next().is_none()).then_some(gap.start * 4000000 + y)
+}
+
+/// Past this many uncovered positions, `uncovered` stops looking for them
+/// one by one and scans the rows instead.
+const MAX_HOLES: usize = 16;
+
+/// The crossings of the lines one and two steps outside each diamond, a
+/// sensor's `(x, y, radius)`, with each other and with the edges of
+/// `limits`, that no diamond covers, row by row.
+fn uncovered_crossings(diamonds: &[(i64, i64, i64)], limits: (i64, i64)) -> Vec<(i64, i64)> {
+    let (low, high) = limits;
+    // The edges of the limits, in rotated coordinates, go in as lines too.
+    let mut us = vec![2 * low, low + high, 2 * high];
+    let mut vs = vec![low - high, 0, high - low];
+    for &(sx, sy, radius) in diamonds.iter() {
+        for outside in [radius + 1, radius + 2] {
+            us.extend([sx + sy - outside, sx + sy + outside]);
+            vs.extend([sx - sy - outside, sx - sy + outside]);
+        }
+    }
+    us.sort_unstable();
+    us.dedup();
+    vs.sort_unstable();
+    vs.dedup();
+    let mut candidates: Vec<(i64, i64)> = vec![];
+    for &u in us.iter() {
+        for &v in vs.iter() {
+            if (u - v) % 2 != 0 {
+                continue;
+            }
+            candidates.push(((u + v) / 2, (u - v) / 2));
+        }
+    }
+    // Points where the lines above cross the edges of the limits.
+    for edge in [low, high] {
+        for &u in us.iter() {
+            candidates.extend([(edge, u - edge), (u - edge, edge)]);
+        }
+        for &v in vs.iter() {
+            candidates.extend([(edge, edge - v), (v + edge, edge)]);
+        }
+    }
+    let square = Interval::new(low, high);
+    candidates.retain(|&(x, y)| {
+        square.contains(x)
+            && square.contains(y)
+            && diamonds
+                .iter()
+                .all(|&(sx, sy, radius)| (x - sx).abs() + (y - sy).abs() > radius)
+    });
+    candidates.sort_by_key(|&(x, y)| (y, x));
+    candidates.dedup();
+    candidates
+}
+
+/// The positions within `limits`, along both axes, that no sensor covers,
+/// as runs along each row, row by row.
+///
+/// Turned 45 degrees, with `u = x + y` and `v = x - y`, each sensor covers
+/// a square. Of the uncovered positions, the one with the largest `u`, and
+/// then the largest `v`, has covered or out of bounds positions on its
+/// right, so it sits on a `u` line and a `v` line one or two steps outside
+/// some sensor's square, or on the edge of the limits. If anything is
+/// uncovered, then, one of those crossings is. The uncovered crossings are
+/// taken as sensors covering just themselves and the crossings tried again,
+/// until none is left, which proves the positions found are all there are.
+/// Past `MAX_HOLES` of them, the rows are scanned instead, one at a time as
+/// the runs are asked for.
+pub fn uncovered(
+    sensors: &[(i64, i64, i64, i64)],
+    limits: (i64, i64),
+) -> impl Iterator<Item = (i64, Interval)> + '_ {
+    let mut diamonds: Vec<(i64, i64, i64)> = sensors
+        .iter()
+        .map(|&(sx, sy, bx, by)| (sx, sy, (sx - bx).abs() + (sy - by).abs()))
+        .collect();
+    let mut found = vec![];
+    let proven = loop {
+        let holes = uncovered_crossings(&diamonds, limits);
+        if holes.is_empty() {
+            break true;
+        }
+        if found.len() + holes.len() > MAX_HOLES {
+            found.clear();
+            break false;
+        }
+        diamonds.extend(holes.iter().map(|&(x, y)| (x, y, 0)));
+        found.extend(holes);
+    };
+    found.sort_by_key(|&(x, y)| (y, x));
+    let mut runs: Vec<(i64, Interval)> = vec![];
+    for (x, y) in found {
+        match runs.last_mut() {
+            Some((line, run)) if *line == y && run.end + 1 == x => run.end = x,
+            _ => runs.push((y, Interval::new(x, x))),
+        }
+    }
+
+    let (low, high) = limits;
+    let square = Interval::new(low, high);
+    let scan = (!proven).then(|| {
+        (low..=high).flat_map(move |line| {
+            coverage(sensors, line)
+                .gaps(square)
+                .into_iter()
+                .map(move |gap| (line, gap))
+        })
+    });
+    runs.into_iter().chain(scan.into_iter().flatten())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 15;
+    type Input = Vec<(i64, i64, i64, i64)>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[test]
+fn test_day15_prep() {
+    assert_eq!(
+        vec![
+            (2, 18, -2, 15),
+            (9, 16, 10, 16),
+            (13, 2, 15, 3),
+            (12, 14, 10, 16),
+            (10, 20, 10, 16),
+            (14, 17, 10, 16),
+            (8, 7, 2, 10),
+            (2, 0, 2, 10),
+            (0, 11, 2, 10),
+            (20, 14, 25, 17),
+            (17, 20, 21, 22),
+            (16, 7, 15, 3),
+            (14, 3, 15, 3),
+            (20, 1, 15, 3)
+        ],
+        parse(DAY15_EXAMPLE)
+    );
+}
+
+#[test]
+// [7, 1, 3, 4, 4, 5, 9, 10, 3, 8, 6, 5, 1, 7]
+fn test_day15_part1() {
+    assert_eq!(no_beacon_count(&parse(DAY15_EXAMPLE), EXAMPLE_ROW), 26);
+    // Two beacons on the row, each taking one position off.
+    let two_beacons = "Sensor at x=0, y=0: closest beacon is at x=2, y=0
+Sensor at x=10, y=0: closest beacon is at x=8, y=0";
+    assert_eq!(no_beacon_count(&parse(two_beacons), 0), 8);
+}
+
+#[test]
+fn test_day15_part2() {
+    let sensors = parse(DAY15_EXAMPLE);
+    let holes: Vec<_> = uncovered(&sensors, EXAMPLE_LIMITS).collect();
+    assert_eq!(holes, vec![(11, Interval::new(14, 14))]);
+    assert_eq!(Some(56000011), tuning_frequency(&sensors, EXAMPLE_LIMITS));
+    // Widening the limits opens up more holes.
+    let holes = positions(uncovered(&sensors, (-3, 22)));
+    assert_eq!(holes, brute_force_uncovered(&sensors, (-3, 22)));
+    assert!(holes.len() > 1 && holes.contains(&(14, 11)));
+    assert_eq!(tuning_frequency(&sensors, (-3, 22)), None);
+    // With the real limits almost nothing is covered, and that is found out
+    // on the first row.
+    assert_eq!(part2(&sensors), None);
+    let lone = parse("Sensor at x=2000000, y=2000000: closest beacon is at x=2000010, y=2000000");
+    assert_eq!(
+        uncovered(&lone, LIMITS).next(),
+        Some((0, Interval::new(0, 4_000_000)))
+    );
+    assert_eq!(tuning_frequency(&lone, LIMITS), None);
+    // Two holes side by side, only one of them on a crossing.
+    let pair = "Sensor at x=1, y=3: closest beacon is at x=6, y=9
+Sensor at x=11, y=2: closest beacon is at x=15, y=8
+Sensor at x=-2, y=14: closest beacon is at x=-6, y=18
+Sensor at x=3, y=9: closest beacon is at x=9, y=9
+Sensor at x=8, y=0: closest beacon is at x=9, y=0";
+    let holes: Vec<_> = uncovered(&parse(pair), (0, 11)).collect();
+    assert_eq!(holes, vec![(11, Interval::new(8, 9))]);
+    assert_eq!(tuning_frequency(&parse(pair), (0, 11)), None);
+    // A hole in the corner of the limits is found too.
+    let corner = "Sensor at x=6, y=6: closest beacon is at x=6, y=17";
+    let holes: Vec<_> = uncovered(&parse(corner), (0, 10)).collect();
+    assert_eq!(holes, vec![(0, Interval::new(0, 0))]);
+    // A hole on the edge that only a `v` line runs through.
+    let edge = "Sensor at x=9, y=8: closest beacon is at x=15, y=8
+Sensor at x=12, y=-1: closest beacon is at x=16, y=-1
+Sensor at x=3, y=1: closest beacon is at x=9, y=1
+Sensor at x=3, y=7: closest beacon is at x=9, y=7";
+    let diamonds: Vec<_> = parse(edge)
+        .into_iter()
+        .map(|(sx, sy, bx, by)| (sx, sy, (sx - bx).abs() + (sy - by).abs()))
+        .collect();
+    assert_eq!(uncovered_crossings(&diamonds, (0, 10)), vec![(10, 2)]);
+}
+
+#[cfg(test)]
+fn positions(holes: impl Iterator<Item = (i64, Interval)>) -> Vec<(i64, i64)> {
+    holes
+        .flat_map(|(y, gap)| (gap.start..=gap.end).map(move |x| (x, y)))
+        .collect()
+}
+
+#[cfg(test)]
+fn brute_force_uncovered(
+    sensors: &[(i64, i64, i64, i64)],
+    (low, high): (i64, i64),
+) -> Vec<(i64, i64)> {
+    let covered = |x: i64, y: i64| {
+        sensors.iter().any(|&(sx, sy, bx, by)| {
+            (x - sx).abs() + (y - sy).abs() <= (sx - bx).abs() + (sy - by).abs()
+        })
+    };
+    (low..=high)
+        .flat_map(|y| (low..=high).map(move |x| (x, y)))
+        .filter(|&(x, y)| !covered(x, y))
+        .collect()
+}
+
+#[test]
+fn test_day15_uncovered_against_brute_force() {
+    // A small linear congruential generator, so the cases are the same on
+    // every run.
+    let mut seed: u64 = 15;
+    let mut next = |n: i64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as i64 % n
+    };
+    for _ in 0..2000 {
+        let sensors: Vec<_> = (0..1 + next(6))
+            .map(|_| {
+                let (sx, sy) = (next(18) - 3, next(18) - 3);
+                (sx, sy, sx + next(10), sy)
+            })
+            .collect();
+        let limits = (0, next(12));
+        assert_eq!(
+            positions(uncovered(&sensors, limits)),
+            brute_force_uncovered(&sensors, limits),
+            "{sensors:?} within {limits:?}"
+        );
+    }
+}
+
+#[allow(dead_code)]
+const DAY15_EXAMPLE: &str = r#"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3"#;
+
+#[allow(dead_code)]
+const DAY15_EXAMPLE_2: &str = r#"Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10"#;
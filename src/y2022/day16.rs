@@ -0,0 +1,231 @@
+use crate::{Answer, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Each valve's name, flow rate and neighbours.
+pub fn parse_valves(input: &str) -> Vec<(String, i32, Vec<String>)> {
+    let re = Regex::new(r"Valve ([A-Z]{2}) has flow rate=(\d+); tunnel.? lead.? to valve.? (.+)")
+        .unwrap();
+    let mut nodes = vec![];
+    for line in input.lines() {
+        let captures = re.captures(line).unwrap();
+        let mut placeholders = captures.iter();
+        placeholders.next();
+        let name = placeholders.next().unwrap().unwrap().as_str().to_owned();
+        let flow: i32 = placeholders
+            .next()
+            .unwrap()
+            .unwrap()
+            .as_str()
+            .parse()
+            .unwrap();
+        let neighbors: Vec<String> = placeholders
+            .next()
+            .unwrap()
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(|i| i.to_owned())
+            .collect();
+        nodes.push((name, flow, neighbors));
+    }
+    nodes
+}
+#[derive(Debug)]
+pub struct CaveSystem {
+    /// Valve names to indices into `tunnels` and `flows`.
+    pub nodes: HashMap<String, usize>,
+    /// Minutes from one valve to another.
+    pub tunnels: Vec<Vec<i32>>,
+    pub flows: Vec<i32>,
+}
+
+pub fn parse(input: &str) -> CaveSystem {
+    let node_tuples = parse_valves(input);
+    let caves = create_caves(node_tuples);
+    let caves = compress_caves(caves);
+    //println!("{:?}", caves);
+    caves
+}
+
+/// The caves with the shortest distance between every pair of valves.
+pub fn create_caves(node_tuples: Vec<(String, i32, Vec<String>)>) -> CaveSystem {
+    let mut node_names = node_tuples
+        .iter()
+        .map(|(name, _, _)| name)
+        .collect::<Vec<_>>();
+    node_names.sort();
+    let mut nodes = HashMap::new();
+    node_names.iter().enumerate().for_each(|(i, name)| {
+        nodes.insert(name.to_string(), i);
+    });
+    let mut tunnels = vec![vec![9999; nodes.len()]; nodes.len()];
+    let mut flows = vec![0; nodes.len()];
+    for (name, flow, neighbors) in node_tuples.clone() {
+        let i = nodes[&name];
+        flows[i] = flow;
+        for neighbor in neighbors {
+            let j = nodes[&neighbor];
+            tunnels[i][j] = 1;
+        }
+    }
+    for (i, row) in tunnels.iter_mut().enumerate() {
+        row[i] = 0;
+    }
+    // use the floyd-warshall algorithm to find the shortest path between all nodes
+    for k in 0..nodes.len() {
+        for i in 0..nodes.len() {
+            for j in 0..nodes.len() {
+                if tunnels[i][j] > tunnels[i][k] + tunnels[k][j] {
+                    tunnels[i][j] = tunnels[i][k] + tunnels[k][j];
+                }
+            }
+        }
+    }
+    CaveSystem {
+        nodes,
+        tunnels,
+        flows,
+    }
+}
+
+/// Just the valves worth opening, and the starting one.
+pub fn compress_caves(c: CaveSystem) -> CaveSystem {
+    let mut cmp_nodes = HashMap::new();
+    let mut nonzero_nodes: Vec<String> = c
+        .nodes
+        .iter()
+        .filter(|(name, &i)| *name == "AA" || c.flows[i] > 0)
+        .map(|(name, _)| name.to_string())
+        .collect();
+    nonzero_nodes.sort();
+    nonzero_nodes.iter().enumerate().for_each(|(i, name)| {
+        cmp_nodes.insert(name.to_string(), i);
+    });
+    let mut cmp_tunnels = vec![vec![9999; nonzero_nodes.len()]; nonzero_nodes.len()];
+    for (src, src_idx) in cmp_nodes.iter() {
+        for (dst, dst_idx) in cmp_nodes.iter() {
+            cmp_tunnels[*src_idx][*dst_idx] = c.tunnels[c.nodes[src]][c.nodes[dst]];
+        }
+    }
+    let mut cmp_flows = vec![0; nonzero_nodes.len()];
+    for (name, idx) in cmp_nodes.iter() {
+        cmp_flows[*idx] = c.flows[c.nodes[name]];
+    }
+    //println!("{:?}", cmp_nodes);
+    CaveSystem {
+        nodes: cmp_nodes,
+        tunnels: cmp_tunnels,
+        flows: cmp_flows,
+    }
+}
+
+type Cache = HashMap<(i32, usize, usize), i32>;
+
+fn search(time: i32, node: usize, visited: usize, cache: &mut Cache, caves: &CaveSystem) -> i32 {
+    if let Some(x) = cache.get(&(time, node, visited)) {
+        return x.to_owned();
+    }
+    let mut max_flow = 0;
+    for n in 0..caves.nodes.len() {
+        if n == node {
+            continue;
+        }
+        let n_mask = 1 << n;
+        if visited & n_mask != 0 {
+            continue;
+        }
+        let time_remaining = time - caves.tunnels[node][n] - 1;
+        if time_remaining <= 0 {
+            continue;
+        }
+        let n_flow = search(time_remaining, n, visited | n_mask, cache, caves)
+            + caves.flows[n] * time_remaining;
+        if n_flow > max_flow {
+            max_flow = n_flow;
+        }
+    }
+    cache.insert((time, node, visited), max_flow);
+    max_flow
+}
+
+/// Minutes until the volcano erupts.
+pub const PART1_MINUTES: i32 = 30;
+/// Minutes left after teaching an elephant to help.
+pub const PART2_MINUTES: i32 = 26;
+
+pub fn part1(caves: &CaveSystem) -> i32 {
+    most_pressure(caves, PART1_MINUTES)
+}
+
+/// The most pressure one can release in `minutes`.
+pub fn most_pressure(caves: &CaveSystem, minutes: i32) -> i32 {
+    let mut cache = Cache::new();
+
+    search(minutes, 0, 0, &mut cache, caves)
+}
+
+pub fn part2(caves: &CaveSystem) -> i32 {
+    most_pressure_with_elephant(caves, PART2_MINUTES)
+}
+
+/// The most pressure two can release in `minutes`, opening different valves.
+pub fn most_pressure_with_elephant(caves: &CaveSystem, minutes: i32) -> i32 {
+    let mut cache = Cache::new();
+
+    let fullmask = 2_usize.pow(caves.nodes.len() as u32) - 1;
+    let mut max_flow = 0;
+    for partition in 0..(fullmask + 1) {
+        let p1 = search(minutes, 0, partition, &mut cache, caves);
+        let p2 = search(minutes, 0, fullmask ^ partition, &mut cache, caves);
+        if p1 + p2 > max_flow {
+            max_flow = p1 + p2;
+        }
+    }
+    max_flow
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 16;
+    const HEAVY: bool = true;
+    type Input = CaveSystem;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[test]
+fn test_day16_part1() {
+    let caves = parse(DAY16_EXAMPLE);
+    assert_eq!(1651, part1(&caves));
+}
+
+#[test]
+fn test_day16_part2() {
+    let caves = parse(DAY16_EXAMPLE);
+    assert_eq!(1707, part2(&caves));
+}
+
+#[allow(dead_code)]
+const DAY16_EXAMPLE: &str = r#"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II"#;
@@ -0,0 +1,198 @@
+use crate::{Answer, Solution};
+use rayon::prelude::*;
+use regex::Regex;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u32,
+    costs: [[u32; 3]; 4],
+}
+
+pub fn parse(input: &str) -> Vec<Blueprint> {
+    let mut blueprints: Vec<Blueprint> = vec![];
+    let re = Regex::new(r"Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian")
+        .unwrap();
+    for line in input.lines() {
+        let captures: Vec<u32> = re
+            .captures(line)
+            .unwrap()
+            .iter()
+            .skip(1)
+            .map(|m| m.unwrap().as_str().parse::<u32>().unwrap())
+            .collect::<Vec<u32>>();
+        blueprints.push(Blueprint {
+            id: captures[0],
+            costs: [
+                [captures[1], 0, 0],
+                [captures[2], 0, 0],
+                [captures[3], captures[4], 0],
+                [captures[5], 0, captures[6]],
+            ],
+        });
+    }
+    blueprints
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct State {
+    time_left: u32,
+    materials: [u32; 4],
+    robots: [u32; 4],
+}
+
+impl State {
+    fn mine(&self, duration: u32) -> Option<State> {
+        let mut newstate = *self;
+        for i in 0..4 {
+            newstate.materials[i] += newstate.robots[i];
+        }
+        if (newstate.time_left as i32 - duration as i32) < 0 {
+            None
+        } else {
+            newstate.time_left -= duration;
+            Some(newstate)
+        }
+    }
+
+    fn make_robot(&self, blueprint: &Blueprint, robot_type: usize) -> Option<State> {
+        for material_needed in 0..3 {
+            if self.materials[material_needed] < blueprint.costs[robot_type][material_needed] {
+                // println!("{:?} < {:?}", self.materials, blueprint.costs[robot_type]);
+                return None;
+            }
+        }
+        if let Some(mut s) = self.mine(1) {
+            for cost_idx in 0..3 {
+                s.materials[cost_idx] -= blueprint.costs[robot_type][cost_idx]
+            }
+            s.robots[robot_type] += 1;
+
+            return Some(s);
+        }
+        None
+    }
+}
+
+fn is_dominated(current: State, best: &State) -> bool {
+    (current.materials[3]
+        + current.robots[3] * current.time_left
+        + ((current.time_left) * (current.time_left + 1) / 2))
+        <= best.materials[3]
+}
+
+fn fanout(prod_rate_limit: [u32; 3], blueprint: &Blueprint, parent: &State) -> Vec<State> {
+    let mut children = vec![];
+    if let Some(s) = parent.mine(1) {
+        children.push(s);
+    }
+
+    //Prioritize branches that make later robots
+    for robot_type in [3, 2, 1, 0].iter() {
+        //Can we benefit by making more of this robot
+        if *robot_type == 3 || parent.robots[*robot_type] < prod_rate_limit[*robot_type] {
+            if let Some(s) = parent.make_robot(blueprint, *robot_type) {
+                children.push(s);
+            }
+        }
+    }
+    children
+}
+
+fn search(blueprint: &Blueprint, time: u32) -> u32 {
+    let initial = State {
+        time_left: time,
+        materials: [0, 0, 0, 0],
+        robots: [1, 0, 0, 0],
+    };
+    let mut visited: HashSet<State> = HashSet::new();
+    let mut stack: Vec<State> = Vec::new();
+    let mut best: State = initial;
+
+    let mut prod_rate_limit: [u32; 3] = [0, 0, 0];
+    for (i, limit) in prod_rate_limit.iter_mut().enumerate() {
+        *limit = blueprint.costs.iter().map(|cost| cost[i]).max().unwrap();
+    }
+    stack.push(initial);
+
+    while let Some(current) = stack.pop() {
+        if current.materials[3] > best.materials[3] {
+            best = current;
+        }
+        if current.time_left > 0 && !is_dominated(current, &best) && !visited.contains(&current) {
+            visited.insert(current);
+            stack.extend(fanout(prod_rate_limit, blueprint, &current).iter());
+        }
+    }
+    best.materials[3]
+}
+
+/// Minutes to crack geodes in.
+pub const PART1_MINUTES: u32 = 24;
+/// Minutes to crack geodes in once the elephants have eaten most blueprints.
+pub const PART2_MINUTES: u32 = 32;
+
+pub fn part1(blueprints: &[Blueprint]) -> u32 {
+    quality_levels(blueprints, PART1_MINUTES)
+}
+
+/// The sum of every blueprint's id times the most geodes it cracks in
+/// `minutes`.
+pub fn quality_levels(blueprints: &[Blueprint], minutes: u32) -> u32 {
+    blueprints
+        .into_par_iter()
+        .map(|x| {
+            let maxg = search(x, minutes);
+            x.id * maxg
+        })
+        .sum()
+}
+
+pub fn part2(blueprints: &[Blueprint]) -> u32 {
+    geode_product(blueprints, PART2_MINUTES)
+}
+
+/// The product of the most geodes each of the first three blueprints
+/// cracks in `minutes`.
+pub fn geode_product(blueprints: &[Blueprint], minutes: u32) -> u32 {
+    //Doing all three blueprints in parallel may use a lot of memory
+    //It might be better run these sequentially for low-memory machines.
+    blueprints
+        .into_par_iter() //This should be .iter() for sequential operation, .into_par_iter() for parallel
+        .take(3)
+        .map(|x| search(x, minutes))
+        .product()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 19;
+    const HEAVY: bool = true;
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[test]
+fn test_day19_part1() {
+    let blueprints = parse(&crate::read_input(2022, 19).expect("Day 19: cannot read input"));
+    println!("{:?}", part1(&blueprints));
+}
+
+#[test]
+fn test_day19_part2() {}
+
+#[allow(dead_code)]
+const DAY19_EXAMPLE: &str = r#""#;
@@ -0,0 +1,85 @@
+use crate::{Answer, Solution};
+
+pub fn parse(input: &str) -> Vec<i64> {
+    let mut ciphertext: Vec<i64> = vec![];
+    for line in input.lines() {
+        ciphertext.push(line.parse().unwrap());
+    }
+    ciphertext
+}
+
+fn mix(ciphertext: &[i64], repeat: usize) -> Vec<i64> {
+    let n = ciphertext.len();
+    let mut mix: Vec<usize> = (0..n).collect();
+    for _ in 0..repeat {
+        for (i, shift) in ciphertext.iter().enumerate() {
+            let idx_src = mix.iter().position(|x| x == &i).unwrap();
+            let idx_to_dst = ((idx_src as i64) + shift).rem_euclid((n - 1) as i64) as usize;
+            mix.remove(idx_src);
+            mix.insert(idx_to_dst, i)
+        }
+    }
+    let mut result = vec![0; ciphertext.len()];
+    for (i, v) in mix.iter().enumerate() {
+        result[i] = ciphertext[v.to_owned()];
+    }
+    result
+}
+
+pub fn part1(ciphertext: &[i64]) -> i64 {
+    let plaintext = mix(ciphertext, 1);
+    let root_pos = plaintext.iter().position(|v| *v == 0i64).unwrap();
+    let m = ciphertext.len();
+    plaintext[(1000 + root_pos) % m]
+        + plaintext[(2000 + root_pos) % m]
+        + plaintext[(3000 + root_pos) % m]
+}
+
+pub fn part2(ciphertext: &[i64]) -> i64 {
+    let keyed_ciphertext: Vec<i64> = ciphertext.iter().map(|x| x * 811589153).collect();
+    let plaintext = mix(&keyed_ciphertext, 10);
+    let root_pos = plaintext.iter().position(|v| *v == 0i64).unwrap();
+    let m = ciphertext.len();
+    plaintext[(1000 + root_pos) % m]
+        + plaintext[(2000 + root_pos) % m]
+        + plaintext[(3000 + root_pos) % m]
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2022;
+    const DAY: usize = 20;
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[test]
+fn test_day20_part1() {
+    println!("{:?}", part1(&parse(DAY20_EXAMPLE)))
+}
+
+#[test]
+fn test_day20_part2() {
+    println!("{:?}", part2(&parse(DAY20_EXAMPLE)))
+}
+
+#[allow(dead_code)]
+const DAY20_EXAMPLE: &str = r#"1
+2
+-3
+3
+-2
+0
+4"#;